@@ -15,7 +15,8 @@ fn main() -> anyhow::Result<()> {
     ])
     .title("What are your favorite fruits?")
     .checkbox_lines(5)
-    .prompt()?;
+    .prompt()?
+    .enable_mouse();
     println!("result: {:?}", p.run()?);
     Ok(())
 }
@@ -0,0 +1,24 @@
+use promkit::preset::{
+    checkbox::Checkbox, container::Container, listbox::Listbox, readline::Readline,
+};
+
+fn main() -> anyhow::Result<()> {
+    let mut p = Container::new()
+        .component(Readline::default().title("What is your name?").prompt()?)
+        .component(
+            Listbox::new(["apple", "banana", "cherry"])
+                .title("Pick a fruit")
+                .prompt()?,
+        )
+        .component(
+            Checkbox::new(["milk", "sugar", "honey"])
+                .title("Add toppings")
+                .prompt()?,
+        )
+        .prompt()?;
+    let mut results = p.run()?;
+    println!("name: {:?}", results.take::<String>(0));
+    println!("fruit: {:?}", results.take::<String>(1));
+    println!("toppings: {:?}", results.take::<Vec<String>>(2));
+    Ok(())
+}
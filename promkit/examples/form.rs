@@ -12,6 +12,7 @@ fn main() -> anyhow::Result<()> {
             inactive_char_style: StyleBuilder::new().build(),
             edit_mode: Default::default(),
             word_break_chars: Default::default(),
+            paste_newlines: Default::default(),
             lines: Default::default(),
         },
         text_editor::State {
@@ -24,6 +25,7 @@ fn main() -> anyhow::Result<()> {
             inactive_char_style: StyleBuilder::new().build(),
             edit_mode: Default::default(),
             word_break_chars: Default::default(),
+            paste_newlines: Default::default(),
             lines: Default::default(),
         },
         text_editor::State {
@@ -36,6 +38,7 @@ fn main() -> anyhow::Result<()> {
             inactive_char_style: StyleBuilder::new().build(),
             edit_mode: Default::default(),
             word_break_chars: Default::default(),
+            paste_newlines: Default::default(),
             lines: Default::default(),
         },
     ])
@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+use promkit::preset::readline::Readline;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut p = Readline::default()
+        .title("Answer within 10 seconds")
+        .prompt()?;
+
+    tokio::select! {
+        ret = p.run_async() => println!("result: {:?}", ret?),
+        _ = tokio::time::sleep(Duration::from_secs(10)) => println!("Timed out"),
+    }
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use promkit::preset::{confirm::Confirm, listbox::Listbox, readline::Readline, wizard::Wizard};
+
+fn main() -> anyhow::Result<()> {
+    let mut p = Wizard::new()
+        .step(Readline::default().title("Project name").prompt()?)
+        .step(Confirm::new("Add a license?").prompt()?)
+        .step_if(
+            |answers| {
+                answers
+                    .get::<String>(1)
+                    .is_some_and(|yes| matches!(yes.as_str(), "y" | "yes" | "Y" | "Yes"))
+            },
+            Listbox::new(["MIT", "Apache-2.0", "GPL-3.0"])
+                .title("Which license?")
+                .prompt()?,
+        )
+        .prompt()?;
+    let mut answers = p.run()?;
+    println!("name: {:?}", answers.take::<String>(0));
+    println!("license: {:?}", answers.take::<String>(2));
+    Ok(())
+}
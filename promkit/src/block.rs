@@ -0,0 +1,344 @@
+//! Decorates panes with borders, padding and a title.
+//!
+//! A [`Block`] wraps any `PaneFactory`, e.g. to draw a boxed preview
+//! or a framed section of a form:
+//!
+//! ```ignore
+//! fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+//!     vec![Block::new(&self.preview_snapshot)
+//!         .border(BorderStyle::Rounded)
+//!         .padding(Padding::horizontal(1))
+//!         .title("Preview")
+//!         .create_pane(width, height)]
+//! }
+//! ```
+
+use crate::{
+    crossterm::style::ContentStyle,
+    grapheme::{StyledGrapheme, StyledGraphemes},
+    layout::merge_attributes,
+    pane::Pane,
+    PaneFactory,
+};
+
+/// The set of characters used to draw a border.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// `┌─┐│└┘`
+    #[default]
+    Single,
+    /// `╔═╗║╚╝`
+    Double,
+    /// `╭─╮│╰╯`
+    Rounded,
+    /// `+-+|++`
+    Ascii,
+}
+
+impl BorderStyle {
+    /// Returns the characters as
+    /// `(top left, top right, bottom left, bottom right, horizontal, vertical)`.
+    fn symbols(&self) -> (char, char, char, char, char, char) {
+        match self {
+            BorderStyle::Single => ('┌', '┐', '└', '┘', '─', '│'),
+            BorderStyle::Double => ('╔', '╗', '╚', '╝', '═', '║'),
+            BorderStyle::Rounded => ('╭', '╮', '╰', '╯', '─', '│'),
+            BorderStyle::Ascii => ('+', '+', '+', '+', '-', '|'),
+        }
+    }
+}
+
+/// The space between a border and the content, in cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Padding {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Padding {
+    /// Creates the same padding on all sides.
+    pub fn uniform(n: usize) -> Self {
+        Self {
+            top: n,
+            right: n,
+            bottom: n,
+            left: n,
+        }
+    }
+
+    /// Creates a padding on the left and right sides only.
+    pub fn horizontal(n: usize) -> Self {
+        Self {
+            right: n,
+            left: n,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a padding on the top and bottom sides only.
+    pub fn vertical(n: usize) -> Self {
+        Self {
+            top: n,
+            bottom: n,
+            ..Default::default()
+        }
+    }
+}
+
+/// Wraps a `PaneFactory` in a border with optional padding and title.
+///
+/// The size passed down to the inner `create_pane`
+/// excludes the border and the padding.
+pub struct Block<P: PaneFactory> {
+    inner: P,
+    /// The border to draw, if any.
+    border: Option<BorderStyle>,
+    /// Style for the border.
+    border_style: ContentStyle,
+    padding: Padding,
+    /// Title embedded in the top border.
+    title: Option<String>,
+    /// Style for the title.
+    title_style: ContentStyle,
+}
+
+impl<P: PaneFactory> Block<P> {
+    /// Wraps the given `PaneFactory` in a single-line border.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            border: Some(BorderStyle::default()),
+            border_style: ContentStyle::default(),
+            padding: Padding::default(),
+            title: None,
+            title_style: ContentStyle::default(),
+        }
+    }
+
+    /// Sets the style of the border.
+    pub fn border(mut self, border: BorderStyle) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Removes the border, e.g. to only add padding.
+    /// The title is not shown without the border.
+    pub fn borderless(mut self) -> Self {
+        self.border = None;
+        self
+    }
+
+    /// Sets the style for the border characters.
+    pub fn border_style(mut self, style: ContentStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the padding between the border and the content.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the title embedded in the top border.
+    pub fn title<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.title = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Sets the style for the title.
+    pub fn title_style(mut self, style: ContentStyle) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Builds the top border with the title embedded, e.g. `┌─ Title ───┐`.
+    fn top_border(
+        &self,
+        top_left: char,
+        top_right: char,
+        horizontal: char,
+        width: usize,
+    ) -> StyledGraphemes {
+        let inner_width = width.saturating_sub(2);
+
+        let mut row = StyledGraphemes::default();
+        row.push_back(StyledGrapheme::new(top_left, self.border_style));
+        let mut used = 0;
+        // Keep a horizontal line on each side of the title.
+        if let Some(title) = self.title.as_ref().filter(|_| inner_width > 2) {
+            row.push_back(StyledGrapheme::new(horizontal, self.border_style));
+            used += 1;
+            for g in StyledGraphemes::from_str(format!(" {} ", title), self.title_style).iter() {
+                if used + g.width() > inner_width - 1 {
+                    break;
+                }
+                used += g.width();
+                row.push_back(g.clone());
+            }
+        }
+        row.extend((used..inner_width).map(|_| StyledGrapheme::new(horizontal, self.border_style)));
+        row.push_back(StyledGrapheme::new(top_right, self.border_style));
+        row
+    }
+}
+
+impl<P: PaneFactory> PaneFactory for Block<P> {
+    fn create_pane(&self, width: u16, height: u16) -> Pane {
+        // Drop the border if there is no room for it and shrink the padding
+        // to leave room for the content, so that the pane never exceeds the given size.
+        let style = self.border.filter(|_| width >= 2 && height >= 2);
+        let border = if style.is_some() { 1 } else { 0 };
+        let (width, height) = (width as usize, height as usize);
+        let room = (width - 2 * border, height - 2 * border);
+        let mut padding = self.padding;
+        padding.left = padding.left.min(room.0.saturating_sub(1));
+        padding.right = padding.right.min(room.0.saturating_sub(1) - padding.left);
+        padding.top = padding.top.min(room.1.saturating_sub(1));
+        padding.bottom = padding.bottom.min(room.1.saturating_sub(1) - padding.top);
+        let inner_width = room.0 - padding.left - padding.right;
+        let inner_height = room.1 - padding.top - padding.bottom;
+
+        let inner = self
+            .inner
+            .create_pane(inner_width as u16, inner_height as u16);
+        let content = if inner_width == 0 || inner_height == 0 {
+            vec![]
+        } else {
+            inner.extract(inner_height)
+        };
+
+        let blank = StyledGraphemes::default();
+        let rows = std::iter::repeat(&blank)
+            .take(padding.top)
+            .chain(content.iter())
+            .chain(std::iter::repeat(&blank).take(padding.bottom))
+            .map(|row| {
+                let right = (inner_width + padding.right).saturating_sub(row.widths());
+                [
+                    StyledGraphemes::from(" ".repeat(padding.left)),
+                    row.clone(),
+                    StyledGraphemes::from(" ".repeat(right)),
+                ]
+                .into_iter()
+                .collect::<StyledGraphemes>()
+            });
+
+        let layout = match style {
+            Some(style) => {
+                let (top_left, top_right, bottom_left, bottom_right, horizontal, vertical) =
+                    style.symbols();
+                let side = StyledGraphemes::from_str(vertical.to_string(), self.border_style);
+                let mut layout = vec![self.top_border(top_left, top_right, horizontal, width)];
+                layout.extend(rows.map(|row| {
+                    [side.clone(), row, side.clone()]
+                        .into_iter()
+                        .collect::<StyledGraphemes>()
+                }));
+                layout.push(StyledGraphemes::from_str(
+                    format!(
+                        "{}{}{}",
+                        bottom_left,
+                        horizontal.to_string().repeat(width - 2),
+                        bottom_right
+                    ),
+                    self.border_style,
+                ));
+                layout
+            }
+            None => rows.collect(),
+        };
+
+        let cursor = inner
+            .extracted_cursor(inner_height)
+            .filter(|_| inner_width > 0)
+            .map(|(row, column)| (border + padding.top + row, border + padding.left + column));
+        let items = std::iter::repeat(None)
+            .take(border + padding.top)
+            .chain(if content.is_empty() {
+                vec![]
+            } else {
+                inner.extracted_items(inner_height)
+            })
+            .collect();
+        let indent = border + padding.left + inner.indent();
+        merge_attributes(
+            Pane::new(layout, 0).with_items(items).with_indent(indent),
+            &[(inner, cursor)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::text;
+
+    fn text(text: &str) -> text::State {
+        text::State {
+            text: text.to_string(),
+            style: ContentStyle::default(),
+        }
+    }
+
+    fn rows(pane: Pane) -> Vec<String> {
+        pane.extract(usize::MAX)
+            .iter()
+            .map(|row| row.to_string())
+            .collect()
+    }
+
+    mod create_pane {
+        use super::*;
+
+        #[test]
+        fn test_border_and_title() {
+            let pane = Block::new(text("abcdef"))
+                .border(BorderStyle::Rounded)
+                .title("Title")
+                .create_pane(8, 10);
+            assert_eq!(vec!["╭─ Tit─╮", "│abcdef│", "╰──────╯"], rows(pane));
+        }
+
+        #[test]
+        fn test_padding() {
+            let pane = Block::new(text("abcdef"))
+                .border(BorderStyle::Ascii)
+                .padding(Padding {
+                    top: 1,
+                    right: 1,
+                    bottom: 0,
+                    left: 2,
+                })
+                .create_pane(8, 10);
+            assert_eq!(
+                vec!["+------+", "|      |", "|  abc |", "|  def |", "+------+"],
+                rows(pane)
+            );
+        }
+
+        #[test]
+        fn test_without_room() {
+            let block = Block::new(text("abc")).padding(Padding::uniform(1));
+            assert_eq!(vec!["a"], rows(block.create_pane(1, 1)));
+            assert_eq!(vec!["┌┐", "└┘"], rows(block.create_pane(2, 2)));
+            assert_eq!(vec!["┌─┐", "│a│", "└─┘"], rows(block.create_pane(3, 3)));
+            assert_eq!(
+                vec!["┌──┐", "│  │", "│ a│", "└──┘"],
+                rows(block.create_pane(4, 4))
+            );
+        }
+
+        #[test]
+        fn test_borderless() {
+            let pane = Block::new(text("ab"))
+                .borderless()
+                .padding(Padding::horizontal(1))
+                .create_pane(8, 10);
+            assert_eq!(vec![" ab     "], rows(pane));
+        }
+    }
+}
@@ -14,3 +14,9 @@ pub trait PaneFactory {
     /// Creates pane with the given width.
     fn create_pane(&self, width: u16, height: u16) -> Pane;
 }
+
+impl<P: PaneFactory + ?Sized> PaneFactory for &P {
+    fn create_pane(&self, width: u16, height: u16) -> Pane {
+        (**self).create_pane(width, height)
+    }
+}
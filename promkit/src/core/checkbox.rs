@@ -115,6 +115,18 @@ impl Checkbox {
         self.listbox.forward()
     }
 
+    /// Moves the cursor to the given position, if possible.
+    /// Returns `true` if the cursor was successfully moved, `false` otherwise.
+    pub fn move_to(&mut self, position: usize) -> bool {
+        self.listbox.move_to(position)
+    }
+
+    /// Finds the position of the item designated by the given answer,
+    /// which is either the 1-based index of the item or its text.
+    pub fn index_of(&self, answer: &str) -> Option<usize> {
+        self.listbox.index_of(answer)
+    }
+
     /// Moves the cursor to the head (beginning) of the listbox.
     pub fn move_to_head(&mut self) {
         self.listbox.move_to_head()
@@ -160,4 +172,19 @@ mod tests {
             assert_eq!(checkbox.picked_indexes(), &expected_picked_indexes);
         }
     }
+
+    mod index_of {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let checkbox = Checkbox::from_displayable(["apple", "banana", "cherry"]);
+            assert_eq!(Some(0), checkbox.index_of("1"));
+            assert_eq!(Some(2), checkbox.index_of(" 3 "));
+            assert_eq!(Some(1), checkbox.index_of("banana"));
+            assert_eq!(None, checkbox.index_of("0"));
+            assert_eq!(None, checkbox.index_of("4"));
+            assert_eq!(None, checkbox.index_of("grape"));
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{crossterm::style::ContentStyle, grapheme::StyledGraphemes, pane::Pane, PaneFactory};
+use crate::{
+    crossterm::style::ContentStyle, grapheme::StyledGraphemes, indicator::Indicator, pane::Pane,
+    PaneFactory,
+};
 
 use super::Checkbox;
 
@@ -28,6 +31,22 @@ pub struct State {
 
     /// Number of lines available for rendering.
     pub lines: Option<usize>,
+    /// The scrollbar and position footer to show.
+    pub indicator: Indicator,
+}
+
+impl State {
+    /// Checks if the given column of a row showing the item
+    /// falls on the mark of the item.
+    pub fn is_on_mark(&self, index: usize, column: usize) -> bool {
+        let start = StyledGraphemes::from(&self.cursor).widths();
+        let mark = if self.checkbox.picked_indexes().contains(&index) {
+            self.active_mark
+        } else {
+            self.inactive_mark
+        };
+        (start..start + StyledGraphemes::from(mark.to_string()).widths()).contains(&column)
+    }
 }
 
 impl PaneFactory for State {
@@ -44,6 +63,7 @@ impl PaneFactory for State {
             Some(lines) => lines.min(height as usize),
             None => height as usize,
         };
+        let (content_width, content_height) = self.indicator.content_size(width, height);
 
         let matrix = self
             .checkbox
@@ -51,7 +71,7 @@ impl PaneFactory for State {
             .iter()
             .enumerate()
             .filter(|(i, _)| {
-                *i >= self.checkbox.position() && *i < self.checkbox.position() + height
+                *i >= self.checkbox.position() && *i < self.checkbox.position() + content_height
             })
             .map(|(i, item)| {
                 if i == self.checkbox.position() {
@@ -68,14 +88,29 @@ impl PaneFactory for State {
                     .apply_style(self.inactive_item_style)
                 }
             })
-            .fold((vec![], 0), |(mut acc, pos), item| {
-                let rows = item.matrixify(width as usize, height, 0).0;
-                if pos < self.checkbox.position() + height {
+            .fold((vec![], vec![], 0), |(mut acc, mut items, pos), item| {
+                let rows = item.matrixify(content_width as usize, content_height, 0).0;
+                if pos < self.checkbox.position() + content_height {
+                    items.extend(
+                        std::iter::repeat(Some(self.checkbox.position() + pos)).take(rows.len()),
+                    );
                     acc.extend(rows);
                 }
-                (acc, pos + 1)
+                (acc, items, pos + 1)
             });
 
-        Pane::new(matrix.0, 0)
+        let (rows, mut items, _) = matrix;
+        items.truncate(content_height);
+        Pane::new(
+            self.indicator.decorate(
+                rows,
+                width,
+                height,
+                self.checkbox.position(),
+                self.checkbox.items().len(),
+            ),
+            0,
+        )
+        .with_items(items)
     }
 }
@@ -157,6 +157,18 @@ impl JsonStream {
         self.cursor.forward()
     }
 
+    /// Moves the cursor to the row at the given position across the JSON stream, if any.
+    ///
+    /// Returns `true` if the cursor was successfully moved, `false` otherwise.
+    pub fn move_to(&mut self, position: usize) -> bool {
+        let current = self.cursor.cross_contents_position();
+        if position < current {
+            self.cursor.shift(current - position, 0)
+        } else {
+            self.cursor.shift(0, position - current)
+        }
+    }
+
     /// Moves the cursor to the head of the JSON stream.
     pub fn move_to_head(&mut self) {
         self.cursor.move_to_head()
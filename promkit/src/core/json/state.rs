@@ -1,6 +1,7 @@
 use crate::{
     crossterm::style::{Attribute, ContentStyle},
     grapheme::StyledGraphemes,
+    indicator::Indicator,
     pane::Pane,
     PaneFactory,
 };
@@ -39,12 +40,20 @@ pub struct State {
 
     /// Number of lines available for rendering.
     pub lines: Option<usize>,
+    /// The scrollbar and position footer to show.
+    pub indicator: Indicator,
 
     /// The number of spaces used for indentation in the rendered JSON structure.
     /// This value multiplies with the indentation level of a JSON element to determine
     /// the total indentation space. For example, an `indent` value of 4 means each
     /// indentation level will be 4 spaces wide.
     pub indent: usize,
+
+    /// Whether long lines wrap onto multiple lines.
+    /// If `false`, they are truncated at the pane width and can be scrolled horizontally.
+    pub wrap: bool,
+    /// The number of columns scrolled horizontally when not wrapping.
+    pub horizontal_offset: usize,
 }
 
 impl State {
@@ -191,6 +200,25 @@ impl State {
             .collect()
     }
 
+    /// Scrolls the lines one column to the left.
+    pub fn scroll_left(&mut self) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the lines one column to the right, up to the end of the longest line.
+    pub fn scroll_right(&mut self) {
+        let longest = self
+            .stream
+            .flatten_kinds()
+            .iter()
+            .map(|kind| self.indent_level(kind) + self.gen_syntax_style(kind).widths())
+            .max()
+            .unwrap_or(0);
+        if self.horizontal_offset + 1 < longest {
+            self.horizontal_offset += 1;
+        }
+    }
+
     pub fn json_str(&self) -> String {
         self.styled_json()
             .into_iter()
@@ -206,23 +234,100 @@ impl PaneFactory for State {
             Some(lines) => lines.min(height as usize),
             None => height as usize,
         };
+        let (content_width, content_height) = self.indicator.content_size(width, height);
 
-        let styled_json = self.styled_json();
-        let matrix = styled_json
+        let matrix = self
+            .styled_json()
             .into_iter()
             .enumerate()
             .filter(|(i, _)| {
                 *i >= self.stream.cursor.cross_contents_position()
-                    && *i < self.stream.cursor.cross_contents_position() + height
+                    && *i < self.stream.cursor.cross_contents_position() + content_height
             })
-            .fold((vec![], 0), |(mut acc, pos), (_, item)| {
-                let rows = item.matrixify(width as usize, height, 0).0;
-                if pos < self.stream.cursor.cross_contents_position() + height {
-                    acc.extend(rows);
-                }
-                (acc, pos + 1)
-            });
+            .fold(
+                (vec![], vec![], 0),
+                |(mut acc, mut items, pos), (_, item)| {
+                    let item = if self.wrap {
+                        item
+                    } else {
+                        item.truncate(content_width as usize, self.horizontal_offset)
+                    };
+                    let rows = item.matrixify(content_width as usize, content_height, 0).0;
+                    let position = self.stream.cursor.cross_contents_position();
+                    if pos < position + content_height {
+                        items.extend(std::iter::repeat(Some(position + pos)).take(rows.len()));
+                        acc.extend(rows);
+                    }
+                    (acc, items, pos + 1)
+                },
+            );
+
+        let (rows, mut items, _) = matrix;
+        items.truncate(content_height);
+        Pane::new(
+            self.indicator.decorate(
+                rows,
+                width,
+                height,
+                self.stream.cursor.cross_contents_position(),
+                self.stream.flatten_kinds().len(),
+            ),
+            0,
+        )
+        .with_items(items)
+    }
+}
 
-        Pane::new(matrix.0, 0)
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::serde_json::Deserializer;
+
+    fn state(json: &str) -> State {
+        let stream = Deserializer::from_str(json)
+            .into_iter::<serde_json::Value>()
+            .filter_map(Result::ok);
+        State {
+            stream: JsonStream::new(stream, None),
+            curly_brackets_style: ContentStyle::default(),
+            square_brackets_style: ContentStyle::default(),
+            key_style: ContentStyle::default(),
+            string_value_style: ContentStyle::default(),
+            number_value_style: ContentStyle::default(),
+            boolean_value_style: ContentStyle::default(),
+            null_value_style: ContentStyle::default(),
+            active_item_attribute: Attribute::NoBold,
+            inactive_item_attribute: Attribute::NoBold,
+            lines: None,
+            indicator: Default::default(),
+            indent: 2,
+            wrap: false,
+            horizontal_offset: 0,
+        }
+    }
+
+    fn rows(state: &State) -> Vec<String> {
+        state
+            .create_pane(4, 10)
+            .extract(usize::MAX)
+            .iter()
+            .map(|row| row.to_string())
+            .collect()
+    }
+
+    mod scroll_right {
+        use super::*;
+
+        #[test]
+        fn test_past_the_end() {
+            let mut state = state(r#"{"key": "value"}"#);
+            for _ in 0..100 {
+                state.scroll_right();
+            }
+            let end = rows(&state);
+            state.scroll_left();
+            assert_ne!(end, rows(&state));
+        }
     }
 }
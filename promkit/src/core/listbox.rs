@@ -43,6 +43,16 @@ impl Listbox {
         self.0.contents()
     }
 
+    /// Appends the given items to the end of the listbox,
+    /// keeping the cursor at the current item.
+    pub fn extend<E: fmt::Display, I: IntoIterator<Item = E>>(&mut self, items: I) {
+        self.0.contents_mut().extend(
+            items
+                .into_iter()
+                .map(|e| StyledGraphemes::from(format!("{}", e))),
+        );
+    }
+
     /// Returns the current position of the cursor within the listbox.
     pub fn position(&self) -> usize {
         self.0.position()
@@ -70,6 +80,25 @@ impl Listbox {
         self.0.forward()
     }
 
+    /// Moves the cursor to the given position, if possible.
+    /// Returns `true` if the cursor was successfully moved, `false` otherwise.
+    pub fn move_to(&mut self, position: usize) -> bool {
+        self.0.move_to(position)
+    }
+
+    /// Finds the position of the item designated by the given answer,
+    /// which is either the 1-based index of the item or its text.
+    pub fn index_of(&self, answer: &str) -> Option<usize> {
+        let answer = answer.trim();
+        match answer.parse::<usize>() {
+            Ok(index) if (1..=self.items().len()).contains(&index) => Some(index - 1),
+            _ => self
+                .items()
+                .iter()
+                .position(|item| item.to_string() == answer),
+        }
+    }
+
     /// Moves the cursor to the head (beginning) of the listbox.
     pub fn move_to_head(&mut self) {
         self.0.move_to_head()
@@ -1,4 +1,7 @@
-use crate::{crossterm::style::ContentStyle, grapheme::StyledGraphemes, pane::Pane, PaneFactory};
+use crate::{
+    crossterm::style::ContentStyle, grapheme::StyledGraphemes, indicator::Indicator, pane::Pane,
+    PaneFactory,
+};
 
 use super::Listbox;
 
@@ -20,6 +23,35 @@ pub struct State {
 
     /// Number of lines available for rendering.
     pub lines: Option<usize>,
+    /// The scrollbar and position footer to show.
+    pub indicator: Indicator,
+
+    /// Whether long items wrap onto multiple lines.
+    /// If `false`, they are truncated at the pane width and can be scrolled horizontally.
+    pub wrap: bool,
+    /// The number of columns scrolled horizontally when not wrapping.
+    pub horizontal_offset: usize,
+}
+
+impl State {
+    /// Scrolls the items one column to the left.
+    pub fn scroll_left(&mut self) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the items one column to the right, up to the end of the longest item.
+    pub fn scroll_right(&mut self) {
+        let longest = self
+            .listbox
+            .items()
+            .iter()
+            .map(|item| item.widths())
+            .max()
+            .unwrap_or(0);
+        if self.horizontal_offset + 1 < longest {
+            self.horizontal_offset += 1;
+        }
+    }
 }
 
 impl PaneFactory for State {
@@ -28,44 +60,65 @@ impl PaneFactory for State {
             Some(lines) => lines.min(height as usize),
             None => height as usize,
         };
+        let (content_width, content_height) = self.indicator.content_size(width, height);
 
         let matrix = self
             .listbox
             .items()
             .iter()
             .enumerate()
-            .filter(|(i, _)| *i >= self.listbox.position() && *i < self.listbox.position() + height)
+            .filter(|(i, _)| {
+                *i >= self.listbox.position() && *i < self.listbox.position() + content_height
+            })
             .map(|(i, item)| {
-                if i == self.listbox.position() {
-                    let init =
-                        StyledGraphemes::from_iter([&StyledGraphemes::from(&self.cursor), item]);
-                    if let Some(style) = &self.active_item_style {
-                        init.apply_style(*style)
-                    } else {
-                        init
-                    }
+                let (cursor, style) = if i == self.listbox.position() {
+                    (StyledGraphemes::from(&self.cursor), &self.active_item_style)
                 } else {
-                    let init = StyledGraphemes::from_iter([
-                        &StyledGraphemes::from(
+                    (
+                        StyledGraphemes::from(
                             " ".repeat(StyledGraphemes::from(&self.cursor).widths()),
                         ),
-                        item,
-                    ]);
-                    if let Some(style) = &self.inactive_item_style {
-                        init.apply_style(*style)
-                    } else {
-                        init
-                    }
+                        &self.inactive_item_style,
+                    )
+                };
+                let item = if self.wrap {
+                    item.clone()
+                } else {
+                    item.truncate(
+                        (content_width as usize).saturating_sub(cursor.widths()),
+                        self.horizontal_offset,
+                    )
+                };
+                let init = StyledGraphemes::from_iter([cursor, item]);
+                if let Some(style) = style {
+                    init.apply_style(*style)
+                } else {
+                    init
                 }
             })
-            .fold((vec![], 0), |(mut acc, pos), item| {
-                let rows = item.matrixify(width as usize, height, 0).0;
-                if pos < self.listbox.position() + height {
+            .fold((vec![], vec![], 0), |(mut acc, mut items, pos), item| {
+                let rows = item.matrixify(content_width as usize, content_height, 0).0;
+                if pos < self.listbox.position() + content_height {
+                    items.extend(
+                        std::iter::repeat(Some(self.listbox.position() + pos)).take(rows.len()),
+                    );
                     acc.extend(rows);
                 }
-                (acc, pos + 1)
+                (acc, items, pos + 1)
             });
 
-        Pane::new(matrix.0, 0)
+        let (rows, mut items, _) = matrix;
+        items.truncate(content_height);
+        Pane::new(
+            self.indicator.decorate(
+                rows,
+                width,
+                height,
+                self.listbox.position(),
+                self.listbox.items().len(),
+            ),
+            0,
+        )
+        .with_items(items)
     }
 }
@@ -35,6 +35,13 @@ impl<R: PaneFactory + Clone + 'static> Snapshot<R> {
         &self.init
     }
 
+    /// Returns a mutable reference to the initial state (`init`) of the renderer.
+    /// This allows for modifications that should survive `reset_after_to_init`
+    /// or derivations of the `after` state from the `init` state (e.g. filtering).
+    pub fn init_mut(&mut self) -> &mut R {
+        &mut self.init
+    }
+
     /// Returns a reference to the state of the renderer before any changes were applied (`before`).
     pub fn borrow_before(&self) -> Ref<R> {
         self.before.borrow()
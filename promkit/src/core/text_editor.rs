@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::{
     core::cursor::Cursor,
     grapheme::{StyledGrapheme, StyledGraphemes},
@@ -20,6 +22,33 @@ pub enum Mode {
     Overwrite,
 }
 
+/// How newlines in pasted text are handled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum NewlinePolicy {
+    /// Remove the newlines.
+    #[default]
+    Strip,
+    /// Replace each newline with a space.
+    Space,
+    /// Ignore the whole paste if it contains any newline.
+    Reject,
+}
+
+impl NewlinePolicy {
+    /// Applies the policy to the pasted text,
+    /// returning `None` if the paste is rejected.
+    /// `\r\n` and a lone `\r` count as a single newline.
+    pub fn apply(&self, text: &str) -> Option<String> {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        match self {
+            Self::Strip => Some(text.replace('\n', "")),
+            Self::Space => Some(text.replace('\n', " ")),
+            Self::Reject if text.contains('\n') => None,
+            Self::Reject => Some(text),
+        }
+    }
+}
+
 /// A text editor that supports basic editing operations
 /// such as insert, delete, and overwrite.
 /// It utilizes a cursor to navigate and manipulate the text.
@@ -41,8 +70,9 @@ impl TextEditor {
     pub fn new<S: AsRef<str>>(s: S) -> Self {
         let mut buf = s.as_ref().to_owned();
         buf.push(' ');
-        let pos = buf.len() - 1;
-        Self(Cursor::new(StyledGraphemes::from(buf), pos, false))
+        let graphemes = StyledGraphemes::from(buf);
+        let pos = graphemes.len() - 1;
+        Self(Cursor::new(graphemes, pos, false))
     }
 
     /// Returns the current text including the cursor.
@@ -62,57 +92,76 @@ impl TextEditor {
         self.0.position()
     }
 
-    /// Masks all characters except the cursor with the specified mask character.
+    /// Masks all graphemes except the cursor with the specified mask character.
     pub fn masking(&self, mask: char) -> StyledGraphemes {
-        self.text()
-            .chars()
-            .into_iter()
+        let text = self.text();
+        let last = text.len() - 1;
+        text.iter()
             .enumerate()
-            .map(|(i, c)| StyledGrapheme::from(if i == self.text().len() - 1 { c } else { mask }))
+            .map(|(i, g)| {
+                if i == last {
+                    g.clone()
+                } else {
+                    StyledGrapheme::from(mask)
+                }
+            })
             .collect::<StyledGraphemes>()
     }
 
     /// Replaces the current text with new text and positions the cursor at the end.
     pub fn replace(&mut self, new: &str) {
-        let mut buf = new.to_owned();
-        buf.push(' ');
-        let pos = buf.len() - 1;
-        *self = Self(Cursor::new(StyledGraphemes::from(buf), pos, false));
+        *self = Self::new(new);
+    }
+
+    /// Inserts the string at the current cursor position
+    /// and moves the cursor right after it.
+    ///
+    /// The text is segmented into grapheme clusters again,
+    /// so that e.g. a combining accent or a skin tone modifier
+    /// joins the grapheme before the cursor.
+    fn insert_str(&mut self, s: &str) {
+        let pos = self.position();
+        let text = self.text();
+        let mut buf = text
+            .iter()
+            .take(pos)
+            .map(|g| g.as_str())
+            .collect::<String>();
+        buf.push_str(s);
+        let end = buf.len();
+        buf.extend(text.iter().skip(pos).map(|g| g.as_str()));
+
+        let pos = buf.grapheme_indices(true).filter(|(i, _)| *i < end).count();
+        self.0 = Cursor::new(StyledGraphemes::from(buf), pos, false);
     }
 
     /// Inserts a character at the current cursor position.
     pub fn insert(&mut self, ch: char) {
-        let pos = self.position();
-        self.0.contents_mut().insert(pos, StyledGrapheme::from(ch));
-        self.forward();
+        self.insert_str(ch.encode_utf8(&mut [0; 4]));
     }
 
-    pub fn insert_chars(&mut self, vch: &Vec<char>) {
-        for ch in vch {
-            self.insert(*ch);
-        }
+    pub fn insert_chars(&mut self, vch: &[char]) {
+        self.insert_str(&vch.iter().collect::<String>());
     }
 
-    /// Overwrites the character at the current cursor position with the specified character.
+    /// Overwrites the grapheme at the current cursor position with the specified character.
     pub fn overwrite(&mut self, ch: char) {
-        if self.0.is_tail() {
-            self.insert(ch)
-        } else {
-            let pos = self.position();
-            self.0
-                .contents_mut()
-                .replace_range(pos..pos + 1, ch.to_string());
-            self.forward();
-        }
+        self.overwrite_chars(&[ch]);
     }
 
-    pub fn overwrite_chars(&mut self, vch: &Vec<char>) {
-        for ch in vch {
-            self.overwrite(*ch);
+    /// Overwrites one grapheme at the current cursor position
+    /// for each grapheme cluster formed by the characters.
+    pub fn overwrite_chars(&mut self, vch: &[char]) {
+        for cluster in vch.iter().collect::<String>().graphemes(true) {
+            if !self.0.is_tail() {
+                let pos = self.position();
+                self.0.contents_mut().remove(pos);
+            }
+            self.insert_str(cluster);
         }
     }
 
-    /// Erases the character before the cursor position.
+    /// Erases the grapheme before the cursor position.
     pub fn erase(&mut self) {
         if !self.0.is_head() {
             self.backward();
@@ -138,16 +187,24 @@ impl TextEditor {
         }
     }
 
+    /// Returns whether the grapheme is a single character in `word_break_chars`.
+    fn is_word_break(grapheme: &StyledGrapheme, word_break_chars: &HashSet<char>) -> bool {
+        let mut chars = grapheme.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => word_break_chars.contains(&ch),
+            _ => false,
+        }
+    }
+
     /// Finds the nearest previous index of any character in `word_break_chars` from the cursor position.
     fn find_previous_nearest_index(&self, word_break_chars: &HashSet<char>) -> usize {
         let current_position = self.position();
         self.text()
-            .chars()
             .iter()
             .enumerate()
             .filter(|&(i, _)| i < current_position.saturating_sub(1))
             .rev()
-            .find(|&(_, c)| word_break_chars.contains(c))
+            .find(|&(_, g)| Self::is_word_break(g, word_break_chars))
             .map(|(i, _)| i + 1)
             .unwrap_or(0)
     }
@@ -168,11 +225,10 @@ impl TextEditor {
     fn find_next_nearest_index(&self, word_break_chars: &HashSet<char>) -> usize {
         let current_position = self.position();
         self.text()
-            .chars()
             .iter()
             .enumerate()
             .filter(|&(i, _)| i > current_position)
-            .find(|&(_, c)| word_break_chars.contains(c))
+            .find(|&(_, g)| Self::is_word_break(g, word_break_chars))
             .map(|(i, _)| {
                 if i < self.0.contents().len() - 1 {
                     i + 1
@@ -282,6 +338,15 @@ mod test {
             assert_eq!(new.position(), txt.position());
         }
 
+        #[test]
+        fn test_for_grapheme_cluster() {
+            let mut txt = TextEditor::new("a👍🏽");
+            assert_eq!(2, txt.position());
+            txt.erase();
+            assert_eq!(StyledGraphemes::from("a "), txt.text());
+            assert_eq!(1, txt.position());
+        }
+
         #[test]
         fn test_at_head() {
             let txt = new_with_position(
@@ -394,6 +459,19 @@ mod test {
             assert_eq!(new.text(), txt.text());
             assert_eq!(new.position(), txt.position());
         }
+
+        #[test]
+        fn test_joining_grapheme_cluster() {
+            let mut txt = TextEditor::default();
+            txt.insert_chars(&['a', 'e']);
+            // The combining accent and the skin tone modifier join the grapheme before the cursor.
+            txt.insert('\u{301}');
+            txt.insert('👍');
+            txt.insert('🏽');
+            assert_eq!(StyledGraphemes::from("ae\u{301}👍🏽 "), txt.text());
+            assert_eq!(4, txt.text().len());
+            assert_eq!(3, txt.position());
+        }
     }
 
     mod overwrite {
@@ -678,4 +756,23 @@ mod test {
             assert_eq!(new.position(), txt.position());
         }
     }
+
+    mod apply {
+        use super::super::*;
+
+        #[test]
+        fn test() {
+            let text = "a\nb\r\nc\rd";
+            assert_eq!(Some(String::from("abcd")), NewlinePolicy::Strip.apply(text));
+            assert_eq!(
+                Some(String::from("a b c d")),
+                NewlinePolicy::Space.apply(text)
+            );
+            assert_eq!(None, NewlinePolicy::Reject.apply(text));
+            assert_eq!(
+                Some(String::from("abc")),
+                NewlinePolicy::Reject.apply("abc")
+            );
+        }
+    }
 }
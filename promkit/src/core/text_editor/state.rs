@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::{crossterm::style::ContentStyle, grapheme::StyledGraphemes, pane::Pane, PaneFactory};
 
-use super::{History, Mode, TextEditor};
+use super::{History, Mode, NewlinePolicy, TextEditor};
 
 #[derive(Clone)]
 pub struct State {
@@ -27,10 +27,32 @@ pub struct State {
     pub edit_mode: Mode,
     /// Characters to be for word break.
     pub word_break_chars: HashSet<char>,
+    /// How newlines in pasted text are handled.
+    pub paste_newlines: NewlinePolicy,
     /// Number of lines available for rendering.
     pub lines: Option<usize>,
 }
 
+impl State {
+    /// Inserts the pasted text at the cursor in the current edit mode,
+    /// handling its newlines as `paste_newlines` specifies.
+    /// The other control characters (e.g. ESC or BEL) are dropped,
+    /// since they would be sent to the terminal as they are when drawn.
+    pub fn paste(&mut self, text: &str) {
+        let Some(text) = self.paste_newlines.apply(text) else {
+            return;
+        };
+        let chars = text
+            .chars()
+            .filter(|ch| !ch.is_control())
+            .collect::<Vec<_>>();
+        match self.edit_mode {
+            Mode::Insert => self.texteditor.insert_chars(&chars),
+            Mode::Overwrite => self.texteditor.overwrite_chars(&chars),
+        }
+    }
+}
+
 impl PaneFactory for State {
     fn create_pane(&self, width: u16, height: u16) -> Pane {
         let mut buf = StyledGraphemes::default();
@@ -44,9 +66,8 @@ impl PaneFactory for State {
             None => self.texteditor.text(),
         };
 
-        let mut styled = text
-            .apply_style(self.inactive_char_style)
-            .apply_style_at(self.texteditor.position(), self.active_char_style);
+        // The active style is applied when drawing, unless the terminal cursor is shown.
+        let mut styled = text.apply_style(self.inactive_char_style);
 
         buf.append(&mut styled);
 
@@ -55,14 +76,28 @@ impl PaneFactory for State {
             None => height as usize,
         };
 
-        let (matrix, offset) = buf.matrixify(
+        let scroll = (StyledGraphemes::from_str(&self.prefix, self.prefix_style).widths()
+            + self.texteditor.position())
+            / width as usize;
+        let (matrix, offset) = buf.matrixify(width as usize, height, scroll);
+
+        // Locate the caret within the rows left by `matrixify`,
+        // which drops the rows above `scroll` that do not fit in the height.
+        let (row, column) = buf.locate(
             width as usize,
-            height,
-            (StyledGraphemes::from_str(&self.prefix, self.prefix_style).widths()
-                + self.texteditor.position())
-                / width as usize,
+            StyledGraphemes::from(&self.prefix).len() + self.texteditor.position(),
         );
+        let total = buf.locate(width as usize, buf.len().saturating_sub(1)).0 + 1;
+        let dropped = scroll
+            .min(total.saturating_sub(1))
+            .min(total.saturating_sub(height));
 
-        Pane::new(matrix, offset)
+        let pane = Pane::new(matrix, offset);
+        match row.checked_sub(dropped) {
+            Some(row) if row < height => pane
+                .with_cursor(row, column)
+                .with_cursor_style(self.active_char_style),
+            _ => pane,
+        }
     }
 }
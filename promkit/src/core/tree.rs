@@ -73,6 +73,13 @@ impl Tree {
         self.cursor.forward()
     }
 
+    /// Moves the cursor to the node at the given position among the visible ones, if any.
+    ///
+    /// Returns `true` if the cursor was successfully moved, `false` otherwise.
+    pub fn move_to(&mut self, position: usize) -> bool {
+        self.cursor.move_to(position)
+    }
+
     /// Moves the cursor to the head of the tree.
     pub fn move_to_head(&mut self) {
         self.cursor.move_to_head()
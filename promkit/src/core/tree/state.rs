@@ -1,4 +1,7 @@
-use crate::{crossterm::style::ContentStyle, grapheme::StyledGraphemes, pane::Pane, PaneFactory};
+use crate::{
+    crossterm::style::ContentStyle, grapheme::StyledGraphemes, indicator::Indicator, pane::Pane,
+    PaneFactory,
+};
 
 use super::{Kind, Tree};
 
@@ -25,12 +28,45 @@ pub struct State {
 
     /// Number of lines available for rendering.
     pub lines: Option<usize>,
+    /// The scrollbar and position footer to show.
+    pub indicator: Indicator,
 
     /// The number of spaces used for indenting child items in the tree.
     /// This value determines how much horizontal space is used to visually
     /// represent the hierarchical structure of the tree. Each level of
     /// indentation typically represents a deeper level in the tree hierarchy.
     pub indent: usize,
+
+    /// Whether long items wrap onto multiple lines.
+    /// If `false`, they are truncated at the pane width and can be scrolled horizontally.
+    pub wrap: bool,
+    /// The number of columns scrolled horizontally when not wrapping.
+    pub horizontal_offset: usize,
+}
+
+impl State {
+    /// Scrolls the items one column to the left.
+    pub fn scroll_left(&mut self) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the items one column to the right, up to the end of the longest item.
+    pub fn scroll_right(&mut self) {
+        let longest = self
+            .tree
+            .kinds()
+            .iter()
+            .map(|kind| match kind {
+                Kind::Folded { id, path } | Kind::Unfolded { id, path } => {
+                    path.len() * self.indent + StyledGraphemes::from(id).widths()
+                }
+            })
+            .max()
+            .unwrap_or(0);
+        if self.horizontal_offset + 1 < longest {
+            self.horizontal_offset += 1;
+        }
+    }
 }
 
 impl PaneFactory for State {
@@ -58,39 +94,63 @@ impl PaneFactory for State {
             Some(lines) => lines.min(height as usize),
             None => height as usize,
         };
+        let (content_width, content_height) = self.indicator.content_size(width, height);
 
         let matrix = self
             .tree
             .kinds()
             .iter()
             .enumerate()
-            .filter(|(i, _)| *i >= self.tree.position() && *i < self.tree.position() + height)
+            .filter(|(i, _)| {
+                *i >= self.tree.position() && *i < self.tree.position() + content_height
+            })
             .map(|(i, kind)| {
-                if i == self.tree.position() {
-                    StyledGraphemes::from_str(
-                        format!("{}{}{}", symbol(kind), " ".repeat(indent(kind)), id(kind),),
-                        self.active_item_style,
-                    )
+                let (symbol, style) = if i == self.tree.position() {
+                    (symbol(kind).to_string(), self.active_item_style)
                 } else {
-                    StyledGraphemes::from_str(
-                        format!(
-                            "{}{}{}",
-                            " ".repeat(StyledGraphemes::from(symbol(kind)).widths()),
-                            " ".repeat(indent(kind)),
-                            id(kind),
-                        ),
+                    (
+                        " ".repeat(StyledGraphemes::from(symbol(kind)).widths()),
                         self.inactive_item_style,
                     )
-                }
+                };
+                let item = StyledGraphemes::from_str(
+                    format!("{}{}", " ".repeat(indent(kind)), id(kind)),
+                    style,
+                );
+                let item = if self.wrap {
+                    item
+                } else {
+                    item.truncate(
+                        (content_width as usize)
+                            .saturating_sub(StyledGraphemes::from(&symbol).widths()),
+                        self.horizontal_offset,
+                    )
+                };
+                StyledGraphemes::from_iter([StyledGraphemes::from_str(symbol, style), item])
             })
-            .fold((vec![], 0), |(mut acc, pos), item| {
-                let rows = item.matrixify(width as usize, height, 0).0;
-                if pos < self.tree.position() + height {
+            .fold((vec![], vec![], 0), |(mut acc, mut items, pos), item| {
+                let rows = item.matrixify(content_width as usize, content_height, 0).0;
+                if pos < self.tree.position() + content_height {
+                    items.extend(
+                        std::iter::repeat(Some(self.tree.position() + pos)).take(rows.len()),
+                    );
                     acc.extend(rows);
                 }
-                (acc, pos + 1)
+                (acc, items, pos + 1)
             });
 
-        Pane::new(matrix.0, 0)
+        let (rows, mut items, _) = matrix;
+        items.truncate(content_height);
+        Pane::new(
+            self.indicator.decorate(
+                rows,
+                width,
+                height,
+                self.tree.position(),
+                self.tree.kinds().len(),
+            ),
+            0,
+        )
+        .with_items(items)
     }
 }
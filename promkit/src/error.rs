@@ -0,0 +1,100 @@
+use std::{fmt, io};
+
+/// Represents the errors that can be returned by a prompt.
+///
+/// This allows callers to tell apart how the user left the prompt
+/// (e.g. interrupted or cancelled) from actual failures such as I/O errors.
+///
+/// Keymaps return these variants wrapped in `anyhow::Error`,
+/// and [`Prompt::run`](crate::Prompt::run) recovers them.
+#[derive(Debug)]
+pub enum PromptError {
+    /// The prompt was interrupted by the user (<kbd>Ctrl + C</kbd>).
+    Interrupted,
+    /// The prompt was cancelled by the user (<kbd>Esc</kbd>).
+    Cancelled,
+    /// The user signaled the end of input (<kbd>Ctrl + D</kbd> on empty input).
+    Eof,
+    /// An I/O error occurred while reading events or rendering.
+    Io(io::Error),
+    /// The prompt requires a terminal, but none is available.
+    NotATty,
+    /// Any other error, e.g. raised by a custom renderer.
+    Other(anyhow::Error),
+}
+
+impl fmt::Display for PromptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PromptError::Interrupted => write!(f, "ctrl+c"),
+            PromptError::Cancelled => write!(f, "cancelled"),
+            PromptError::Eof => write!(f, "end of input"),
+            PromptError::Io(e) => write!(f, "I/O error: {}", e),
+            PromptError::NotATty => write!(f, "not a terminal"),
+            PromptError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for PromptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PromptError::Io(e) => Some(e),
+            PromptError::Other(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PromptError {
+    fn from(e: io::Error) -> Self {
+        PromptError::Io(e)
+    }
+}
+
+impl From<anyhow::Error> for PromptError {
+    /// Recovers the `PromptError` or `io::Error` wrapped in the given error,
+    /// falling back to `PromptError::Other`.
+    fn from(e: anyhow::Error) -> Self {
+        match e.downcast::<PromptError>() {
+            Ok(e) => e,
+            Err(e) => match e.downcast::<io::Error>() {
+                Ok(e) => PromptError::Io(e),
+                Err(e) => PromptError::Other(e),
+            },
+        }
+    }
+}
+
+impl PromptError {
+    /// Returns `true` if the user left the prompt deliberately,
+    /// i.e. the error is `Interrupted`, `Cancelled` or `Eof`.
+    pub fn is_user_exit(&self) -> bool {
+        matches!(
+            self,
+            PromptError::Interrupted | PromptError::Cancelled | PromptError::Eof
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod from_anyhow {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let e = PromptError::from(anyhow::Error::from(PromptError::Cancelled));
+            assert!(matches!(e, PromptError::Cancelled));
+
+            let e = PromptError::from(anyhow::Error::from(io::Error::other("broken")));
+            assert!(matches!(e, PromptError::Io(_)));
+
+            let e = PromptError::from(anyhow::anyhow!("invalid"));
+            assert!(matches!(e, PromptError::Other(_)));
+            assert_eq!("invalid", e.to_string());
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{sync::mpsc, time::Duration};
+
+use crate::crossterm::event::{self, Event};
+
+/// A source of events that drives a prompt.
+///
+/// `Prompt` reads its events through this trait,
+/// so that it can be driven by something other than the terminal,
+/// e.g. a replay file, another thread, or a local socket.
+pub trait EventSource {
+    /// Blocks until the next event is available and returns it.
+    fn read(&mut self) -> anyhow::Result<Event>;
+
+    /// Waits for an event to become available for at most `timeout`.
+    ///
+    /// Returns `true` if an event is available, in which case
+    /// the subsequent `read` returns it without blocking.
+    ///
+    /// By default it returns `true` at once, so that the caller blocks on `read`;
+    /// ticks and updates are then only processed as events arrive.
+    fn poll(&mut self, _timeout: Duration) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+}
+
+impl<S: EventSource + ?Sized> EventSource for Box<S> {
+    fn read(&mut self) -> anyhow::Result<Event> {
+        (**self).read()
+    }
+
+    fn poll(&mut self, timeout: Duration) -> anyhow::Result<bool> {
+        (**self).poll(timeout)
+    }
+}
+
+/// Reads events from the terminal via crossterm. This is the default source of `Prompt`.
+#[derive(Clone, Copy, Default)]
+pub struct CrosstermEventSource;
+
+impl EventSource for CrosstermEventSource {
+    fn read(&mut self) -> anyhow::Result<Event> {
+        event::read().map_err(anyhow::Error::from)
+    }
+
+    fn poll(&mut self, timeout: Duration) -> anyhow::Result<bool> {
+        event::poll(timeout).map_err(anyhow::Error::from)
+    }
+}
+
+/// Reads events sent through a channel.
+///
+/// Any `Event` sent through the paired sender,
+/// including synthetic ones that no terminal would produce,
+/// is passed to `Renderer::evaluate` as is.
+/// Reading fails once all senders have been dropped and the channel is drained.
+pub struct ChannelEventSource {
+    receiver: mpsc::Receiver<Event>,
+    /// An event received by `poll` but not yet returned by `read`.
+    pending: Option<Event>,
+}
+
+impl ChannelEventSource {
+    /// Creates a source reading from the given receiver.
+    pub fn new(receiver: mpsc::Receiver<Event>) -> Self {
+        Self {
+            receiver,
+            pending: None,
+        }
+    }
+
+    /// Creates a source together with the sender to feed it.
+    pub fn channel() -> (mpsc::Sender<Event>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (sender, Self::new(receiver))
+    }
+}
+
+impl EventSource for ChannelEventSource {
+    fn read(&mut self) -> anyhow::Result<Event> {
+        match self.pending.take() {
+            Some(ev) => Ok(ev),
+            None => self
+                .receiver
+                .recv()
+                .map_err(|_| anyhow::anyhow!("Event channel has been disconnected")),
+        }
+    }
+
+    fn poll(&mut self, timeout: Duration) -> anyhow::Result<bool> {
+        if self.pending.is_none() {
+            match self.receiver.recv_timeout(timeout) {
+                Ok(ev) => self.pending = Some(ev),
+                Err(mpsc::RecvTimeoutError::Timeout) => return Ok(false),
+                // Report readiness so that `read` surfaces the disconnection.
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(true),
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod channel_event_source {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let (sender, mut source) = ChannelEventSource::channel();
+            sender.send(Event::FocusGained).unwrap();
+            sender.send(Event::Paste(String::from("data"))).unwrap();
+            drop(sender);
+
+            assert_eq!(Event::FocusGained, source.read().unwrap());
+            assert_eq!(Event::Paste(String::from("data")), source.read().unwrap());
+            assert!(source.read().is_err());
+        }
+
+        #[test]
+        fn test_poll() {
+            let (sender, mut source) = ChannelEventSource::channel();
+            assert!(!source.poll(Duration::from_millis(1)).unwrap());
+
+            sender.send(Event::FocusGained).unwrap();
+            assert!(source.poll(Duration::from_millis(1)).unwrap());
+            assert!(source.poll(Duration::from_millis(1)).unwrap());
+            assert_eq!(Event::FocusGained, source.read().unwrap());
+            assert!(!source.poll(Duration::from_millis(1)).unwrap());
+        }
+    }
+}
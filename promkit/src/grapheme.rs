@@ -4,34 +4,67 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::crossterm::style::{Attribute, ContentStyle};
+use crate::crossterm::style::{Attribute, Color, ContentStyle};
 
-/// Represents a single grapheme (character) with its display width and optional styling.
+/// The interval of the tab stops that tabs are expanded to.
+const TAB_WIDTH: usize = 8;
+
+/// The characters of an extended grapheme cluster.
+/// A single character, by far the most common case, is kept inline without allocation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Cluster {
+    Char([u8; 4], u8),
+    Str(Box<str>),
+}
+
+impl Cluster {
+    fn new(cluster: &str) -> Self {
+        let mut chars = cluster.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => {
+                let mut buf = [0; 4];
+                let len = ch.encode_utf8(&mut buf).len();
+                Self::Char(buf, len as u8)
+            }
+            _ => Self::Str(cluster.into()),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Char(buf, len) => {
+                std::str::from_utf8(&buf[..*len as usize]).expect("a single encoded character")
+            }
+            Self::Str(s) => s,
+        }
+    }
+}
+
+/// Represents a single grapheme, an extended grapheme cluster such as
+/// an emoji ZWJ sequence, a flag or a letter with combining accents,
+/// with its display width and optional styling.
 ///
 /// This structure is similar to `Grapheme` but includes styling information directly.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StyledGrapheme {
-    ch: char,
+    cluster: Cluster,
     width: usize,
     style: ContentStyle,
 }
 
 impl From<char> for StyledGrapheme {
     fn from(ch: char) -> Self {
-        Self {
-            ch,
-            width: UnicodeWidthChar::width(ch).unwrap_or(0),
-            style: ContentStyle::default(),
-        }
+        Self::new(ch, ContentStyle::default())
     }
 }
 
 impl fmt::Display for StyledGraphemes {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for styled_grapheme in self.iter() {
-            write!(f, "{}", styled_grapheme.ch)?;
+            write!(f, "{}", styled_grapheme.as_str())?;
         }
         Ok(())
     }
@@ -40,12 +73,32 @@ impl fmt::Display for StyledGraphemes {
 impl StyledGrapheme {
     pub fn new(ch: char, style: ContentStyle) -> Self {
         Self {
-            ch,
+            cluster: Cluster::new(ch.encode_utf8(&mut [0; 4])),
             width: UnicodeWidthChar::width(ch).unwrap_or(0),
             style,
         }
     }
 
+    /// Creates a grapheme from a string holding one extended grapheme cluster.
+    /// The width is that of the whole cluster, e.g. 2 for `👨‍👩‍👧` or `🇯🇵`.
+    pub fn from_cluster(cluster: &str, style: ContentStyle) -> Self {
+        let mut chars = cluster.chars();
+        let width = match (chars.next(), chars.next()) {
+            (Some(ch), None) => UnicodeWidthChar::width(ch).unwrap_or(0),
+            _ => UnicodeWidthStr::width(cluster),
+        };
+        Self {
+            cluster: Cluster::new(cluster),
+            width,
+            style,
+        }
+    }
+
+    /// Returns the characters of the grapheme cluster.
+    pub fn as_str(&self) -> &str {
+        self.cluster.as_str()
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -114,7 +167,7 @@ impl<S: AsRef<str>> From<S> for StyledGraphemes {
 impl fmt::Debug for StyledGraphemes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for styled_grapheme in self.iter() {
-            write!(f, "{}", styled_grapheme.ch)?;
+            write!(f, "{}", styled_grapheme.as_str())?;
         }
         Ok(())
     }
@@ -124,14 +177,124 @@ impl StyledGraphemes {
     pub fn from_str<S: AsRef<str>>(string: S, style: ContentStyle) -> Self {
         string
             .as_ref()
-            .chars()
-            .map(|ch| StyledGrapheme::new(ch, style))
+            .graphemes(true)
+            .map(|cluster| StyledGrapheme::from_cluster(cluster, style))
+            .collect()
+    }
+
+    /// Parses text containing ANSI escape sequences,
+    /// such as the colored output of `git` or `ls --color`.
+    ///
+    /// SGR sequences (`ESC [ ... m`) set the style of the graphemes that follow them.
+    /// Tabs are expanded to spaces up to the next tab stop, every 8 columns.
+    /// Other escape sequences and control characters, including newlines, are removed
+    /// so that they do not count towards the width.
+    /// Use [`StyledGraphemes::from_ansi_lines`] for text spanning several lines.
+    pub fn from_ansi<S: AsRef<str>>(string: S) -> Self {
+        Self::parse_ansi(string.as_ref())
+            .0
+            .into_iter()
+            .filter(|grapheme| grapheme.as_str() != "\n")
+            .collect()
+    }
+
+    /// Parses text containing ANSI escape sequences as [`StyledGraphemes::from_ansi`] does,
+    /// splitting it into lines at the newlines.
+    /// The style set on a line carries over to the following ones.
+    pub fn from_ansi_lines<S: AsRef<str>>(string: S) -> Vec<Self> {
+        let mut lines = vec![Self::default()];
+        for grapheme in Self::parse_ansi(string.as_ref()).0 {
+            if grapheme.as_str() == "\n" {
+                lines.push(Self::default());
+            } else if let Some(line) = lines.last_mut() {
+                line.push_back(grapheme);
+            }
+        }
+        // As `str::lines`, a trailing newline does not start another line.
+        if lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines
+    }
+
+    /// Parses text containing ANSI escape sequences, keeping the newlines.
+    fn parse_ansi(string: &str) -> Self {
+        let mut text = String::new();
+        // The byte offset of `text` where the current line starts, to expand tabs.
+        let mut line_start = 0;
+        // The style starting at each byte offset of `text`.
+        let mut styles = vec![(0, ContentStyle::default())];
+        let mut chars = string.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1B' => match chars.next() {
+                    // CSI: parameter and intermediate bytes, then a final byte.
+                    Some('[') => {
+                        let mut params = String::new();
+                        for ch in chars.by_ref() {
+                            if ('\x40'..='\x7E').contains(&ch) {
+                                if ch == 'm' {
+                                    let mut style = styles[styles.len() - 1].1;
+                                    apply_sgr(&mut style, &params);
+                                    styles.push((text.len(), style));
+                                }
+                                break;
+                            }
+                            params.push(ch);
+                        }
+                    }
+                    // OSC (e.g. hyperlinks), DCS, SOS, PM and APC: a string terminated by BEL or ST.
+                    Some(']' | 'P' | 'X' | '^' | '_') => {
+                        let mut escaped = false;
+                        for ch in chars.by_ref() {
+                            if ch == '\x07' || (escaped && ch == '\\') {
+                                break;
+                            }
+                            escaped = ch == '\x1B';
+                        }
+                    }
+                    // Intermediate bytes (e.g. character set designation), then a final byte.
+                    Some(' '..='/') => {
+                        for ch in chars.by_ref() {
+                            if !(' '..='/').contains(&ch) {
+                                break;
+                            }
+                        }
+                    }
+                    // Two-character sequences.
+                    _ => {}
+                },
+                '\t' => {
+                    let column = UnicodeWidthStr::width(&text[line_start..]);
+                    text.extend(std::iter::repeat(' ').take(TAB_WIDTH - column % TAB_WIDTH));
+                }
+                '\n' => {
+                    text.push(ch);
+                    line_start = text.len();
+                }
+                ch if ch.is_control() => {}
+                ch => text.push(ch),
+            }
+        }
+
+        let mut styles = styles.into_iter().peekable();
+        let mut style = ContentStyle::default();
+        text.grapheme_indices(true)
+            .map(|(i, cluster)| {
+                while let Some((_, next)) = styles.next_if(|(start, _)| *start <= i) {
+                    style = next;
+                }
+                StyledGrapheme::from_cluster(cluster, style)
+            })
             .collect()
     }
 
     /// Returns a `Vec<char>` containing the characters of all `Grapheme` instances in the collection.
     pub fn chars(&self) -> Vec<char> {
-        self.0.iter().map(|grapheme| grapheme.ch).collect()
+        self.0
+            .iter()
+            .flat_map(|grapheme| grapheme.as_str().chars())
+            .collect()
     }
 
     /// Calculates the total display width of all `Grapheme` instances in the collection.
@@ -141,8 +304,8 @@ impl StyledGraphemes {
 
     /// Replaces all occurrences of a substring `from` with another substring `to` within the `StyledGraphemes`.
     pub fn replace<S: AsRef<str>>(mut self, from: S, to: S) -> Self {
-        let from_len = from.as_ref().chars().count();
-        let to_len = to.as_ref().chars().count();
+        let from_len = from.as_ref().graphemes(true).count();
+        let to_len = to.as_ref().graphemes(true).count();
 
         let mut offset = 0;
         let diff = from_len.abs_diff(to_len);
@@ -201,14 +364,14 @@ impl StyledGraphemes {
 
         let mut indices = Vec::new();
         let mut pos = 0;
-        let query_chars: Vec<char> = query_str.chars().collect();
-        let query_len = query_chars.len();
+        let query_clusters: Vec<&str> = query_str.graphemes(true).collect();
+        let query_len = query_clusters.len();
 
         // Iterate through each grapheme in self
         while pos + query_len <= self.0.len() {
             let mut match_found = true;
-            for (i, query_char) in query_chars.iter().enumerate() {
-                if self.0[pos + i].ch != *query_char {
+            for (i, query_cluster) in query_clusters.iter().enumerate() {
+                if self.0[pos + i].as_str() != *query_cluster {
                     match_found = false;
                     break;
                 }
@@ -245,7 +408,7 @@ impl StyledGraphemes {
             return None;
         }
 
-        let query_len = query_str.chars().count();
+        let query_len = query_str.graphemes(true).count();
 
         for &start_index in &indices {
             for i in start_index..start_index + query_len {
@@ -318,6 +481,142 @@ impl StyledGraphemes {
 
         (Vec::from(all), offset)
     }
+
+    /// Returns the location as `(row, column)` of the grapheme at `index`
+    /// within the rows produced by `matrixify` with the given width and no height limit.
+    /// An `index` past the end is located right after the last grapheme.
+    pub fn locate(&self, width: usize, index: usize) -> (usize, usize) {
+        let (mut row, mut column) = (0, 0);
+        for (i, styled) in self.iter().enumerate() {
+            // Wrap in the same way as `matrixify`.
+            if column > 0 && width < column + styled.width {
+                row += 1;
+                column = 0;
+            }
+            if i == index {
+                return (row, column);
+            }
+            if width >= styled.width {
+                column += styled.width;
+            }
+        }
+        (row, column)
+    }
+
+    /// Fits the `StyledGraphemes` into a single row of the given width without wrapping,
+    /// skipping the first `offset` columns for horizontal scrolling.
+    /// The content cut off on either side is marked with `…`.
+    pub fn truncate(&self, width: usize, offset: usize) -> StyledGraphemes {
+        if width == 0 {
+            return StyledGraphemes::default();
+        }
+
+        let mut column = 0;
+        let mut visible = self
+            .iter()
+            .filter(|g| {
+                let start = column;
+                column += g.width;
+                start >= offset
+            })
+            .cloned()
+            .collect::<VecDeque<_>>();
+        if offset > 0 && !self.is_empty() {
+            let style = visible.front().map(|g| g.style).unwrap_or_default();
+            while visible.front().is_some_and(|g| g.width == 0) {
+                visible.pop_front();
+            }
+            visible.pop_front();
+            visible.push_front(StyledGrapheme::new('…', style));
+        }
+
+        if visible.iter().map(|g| g.width).sum::<usize>() > width {
+            let style = visible.back().map(|g| g.style).unwrap_or_default();
+            let mut row = VecDeque::new();
+            let mut used = 0;
+            for g in visible {
+                if used + g.width > width - 1 {
+                    break;
+                }
+                used += g.width;
+                row.push_back(g);
+            }
+            row.push_back(StyledGrapheme::new('…', style));
+            visible = row;
+        }
+        StyledGraphemes(visible)
+    }
+}
+
+/// Applies the parameters of an SGR (`ESC [ ... m`) sequence to the style.
+pub(crate) fn apply_sgr(style: &mut ContentStyle, params: &str) {
+    let params = params.split(';').collect::<Vec<_>>();
+    let mut i = 0;
+    while i < params.len() {
+        match params[i] {
+            "" | "0" => *style = ContentStyle::default(),
+            "39" => style.foreground_color = None,
+            "49" => style.background_color = None,
+            "59" => style.underline_color = None,
+            code @ ("38" | "48" | "58") => {
+                let len = match params.get(i + 1) {
+                    Some(&"5") => 2,
+                    Some(&"2") => 4,
+                    _ => 0,
+                };
+                let color = params
+                    .get(i + 1..i + 1 + len)
+                    .and_then(|values| Color::parse_ansi(&values.join(";")));
+                match code {
+                    "38" => style.foreground_color = color,
+                    "48" => style.background_color = color,
+                    _ => style.underline_color = color,
+                }
+                i += len;
+            }
+            code => match code.parse::<u8>() {
+                // The 16 basic colors, where 90-97 and 100-107 are the bright ones.
+                Ok(n @ 30..=37) => style.foreground_color = basic_color(n - 30),
+                Ok(n @ 90..=97) => style.foreground_color = basic_color(n - 90 + 8),
+                Ok(n @ 40..=47) => style.background_color = basic_color(n - 40),
+                Ok(n @ 100..=107) => style.background_color = basic_color(n - 100 + 8),
+                // Codes turning attributes off reset all the attributes they cover.
+                Ok(22) => unset(style, &[Attribute::Bold, Attribute::Dim]),
+                Ok(23) => unset(style, &[Attribute::Italic, Attribute::Fraktur]),
+                Ok(24) => unset(
+                    style,
+                    &[
+                        Attribute::Underlined,
+                        Attribute::DoubleUnderlined,
+                        Attribute::Undercurled,
+                        Attribute::Underdotted,
+                        Attribute::Underdashed,
+                    ],
+                ),
+                Ok(25) => unset(style, &[Attribute::SlowBlink, Attribute::RapidBlink]),
+                Ok(27) => unset(style, &[Attribute::Reverse]),
+                Ok(28) => unset(style, &[Attribute::Hidden]),
+                Ok(29) => unset(style, &[Attribute::CrossedOut]),
+                _ => {
+                    if let Some(attr) = Attribute::iterator().find(|attr| attr.sgr() == code) {
+                        style.attributes.set(attr);
+                    }
+                }
+            },
+        }
+        i += 1;
+    }
+}
+
+/// Returns the color of the 16 basic colors in the same way as `38;5;n` is parsed.
+fn basic_color(n: u8) -> Option<Color> {
+    Color::parse_ansi(&format!("5;{}", n))
+}
+
+fn unset(style: &mut ContentStyle, attrs: &[Attribute]) {
+    for attr in attrs {
+        style.attributes.unset(*attr);
+    }
 }
 
 pub struct StyledGraphemesDisplay<'a> {
@@ -327,7 +626,11 @@ pub struct StyledGraphemesDisplay<'a> {
 impl<'a> fmt::Display for StyledGraphemesDisplay<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for styled_grapheme in self.styled_graphemes.iter() {
-            write!(f, "{}", styled_grapheme.style.apply(styled_grapheme.ch))?;
+            write!(
+                f,
+                "{}",
+                styled_grapheme.style.apply(styled_grapheme.as_str())
+            )?;
         }
         Ok(())
     }
@@ -349,6 +652,86 @@ mod test {
         }
     }
 
+    mod from_ansi {
+        use crate::{crossterm::style::Color, style::StyleBuilder};
+
+        use super::*;
+
+        #[test]
+        fn test() {
+            let graphemes = StyledGraphemes::from_ansi(
+                "\x1b[1;31mM\x1b[0m a\x1b[38;5;208mb\x1b[48;2;1;2;3mc\x1b[22;39;49m d\x1b[94me",
+            );
+            assert_eq!("M abc de", graphemes.to_string());
+            assert_eq!(8, graphemes.widths());
+            assert_eq!(
+                StyleBuilder::new()
+                    .fgc(Color::DarkRed)
+                    .attrs(Attribute::Bold.into())
+                    .build(),
+                graphemes[0].style
+            );
+            assert_eq!(ContentStyle::default(), graphemes[1].style);
+            assert_eq!(
+                StyleBuilder::new().fgc(Color::AnsiValue(208)).build(),
+                graphemes[3].style
+            );
+            assert_eq!(
+                StyleBuilder::new()
+                    .fgc(Color::AnsiValue(208))
+                    .bgc(Color::Rgb { r: 1, g: 2, b: 3 })
+                    .build(),
+                graphemes[4].style
+            );
+            assert_eq!(ContentStyle::default(), graphemes[6].style);
+            assert_eq!(
+                StyleBuilder::new().fgc(Color::Blue).build(),
+                graphemes[7].style
+            );
+        }
+
+        #[test]
+        fn test_strip_control_sequences() {
+            let graphemes = StyledGraphemes::from_ansi(
+                "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07\x1b[2K\x1b(B\x1b7ok\r\n",
+            );
+            assert_eq!("linkok", graphemes.to_string());
+            assert!(graphemes.iter().all(|g| g.style == ContentStyle::default()));
+        }
+
+        #[test]
+        fn test_expand_tabs() {
+            let graphemes = StyledGraphemes::from_ansi("\x1b[31mab\x1b[0m\tc\tあ\td");
+            assert_eq!("ab      c       あ      d", graphemes.to_string());
+            assert_eq!(25, graphemes.widths());
+        }
+    }
+
+    mod from_ansi_lines {
+        use crate::{crossterm::style::Color, style::StyleBuilder};
+
+        use super::*;
+
+        #[test]
+        fn test() {
+            let lines = StyledGraphemes::from_ansi_lines("a\x1b[31mb\r\n\tc\n\nd\x1b[0m\n");
+            assert_eq!(
+                vec!["ab", "        c", "", "d"],
+                lines
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<_>>()
+            );
+            let red = StyleBuilder::new().fgc(Color::DarkRed).build();
+            assert_eq!(ContentStyle::default(), lines[0][0].style);
+            assert_eq!(red, lines[0][1].style);
+            // The style carries over to the following lines.
+            assert_eq!(red, lines[1][8].style);
+            assert_eq!(red, lines[3][0].style);
+            assert!(StyledGraphemes::from_ansi_lines("").is_empty());
+        }
+    }
+
     mod chars {
         use super::*;
 
@@ -368,6 +751,14 @@ mod test {
             let graphemes = StyledGraphemes::from("a b");
             assert_eq!(3, graphemes.widths()); // 'a' and 'b' are each 1 width, and space is 1 width
         }
+
+        #[test]
+        fn test_with_grapheme_clusters() {
+            // A ZWJ sequence, a flag, a skin tone modifier and a combining accent.
+            let graphemes = StyledGraphemes::from("👨\u{200d}👩\u{200d}👧🇯🇵👍🏽e\u{301}");
+            assert_eq!(4, graphemes.len());
+            assert_eq!(7, graphemes.widths());
+        }
     }
 
     mod replace_char {
@@ -510,6 +901,14 @@ mod test {
             assert_eq!(indices, vec![0], "Should match the entire string");
         }
 
+        #[test]
+        fn test_with_grapheme_clusters() {
+            let graphemes = StyledGraphemes::from("e\u{301}e🇯🇵🇺🇸");
+            assert_eq!(vec![1], graphemes.find_all("e"));
+            assert_eq!(vec![0], graphemes.find_all("e\u{301}"));
+            assert_eq!(vec![3], graphemes.find_all("🇺🇸"));
+        }
+
         #[test]
         fn test_with_partial_overlap() {
             let graphemes = StyledGraphemes::from("ababa");
@@ -558,6 +957,43 @@ mod test {
         }
     }
 
+    mod locate {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let input = StyledGraphemes::from("12345あ7");
+            assert_eq!((0, 0), input.locate(3, 0));
+            assert_eq!((0, 2), input.locate(3, 2));
+            assert_eq!((1, 0), input.locate(3, 3));
+            // The wide character does not fit in the rest of the row.
+            assert_eq!((2, 0), input.locate(3, 5));
+            assert_eq!((2, 2), input.locate(3, 6));
+            assert_eq!((2, 3), input.locate(3, 7));
+        }
+    }
+
+    mod truncate {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let input = StyledGraphemes::from("1234567890");
+            assert_eq!("1234567890", input.truncate(10, 0).to_string());
+            assert_eq!("1234…", input.truncate(5, 0).to_string());
+            assert_eq!("…567…", input.truncate(5, 3).to_string());
+            assert_eq!("…890", input.truncate(10, 6).to_string());
+            assert_eq!("", input.truncate(0, 0).to_string());
+        }
+
+        #[test]
+        fn test_with_wide_characters() {
+            let input = StyledGraphemes::from("あいうえお");
+            assert_eq!("あい…", input.truncate(6, 0).to_string());
+            assert_eq!("…えお", input.truncate(10, 4).to_string());
+        }
+    }
+
     #[cfg(test)]
     mod matrixify {
         use super::*;
@@ -612,6 +1048,15 @@ mod test {
             assert_eq!(offset, 1);
         }
 
+        #[test]
+        fn test_with_grapheme_clusters() {
+            let input = StyledGraphemes::from("a👨\u{200d}👩\u{200d}👧🇯🇵");
+            let (matrix, _) = input.matrixify(3, 2, 0);
+            assert_eq!(2, matrix.len());
+            assert_eq!("a👨\u{200d}👩\u{200d}👧", matrix[0].to_string());
+            assert_eq!("🇯🇵", matrix[1].to_string());
+        }
+
         #[test]
         fn test_with_large_offset() {
             let input = StyledGraphemes::from("1234567890");
@@ -0,0 +1,159 @@
+//! Shows where the cursor is within a scrolling list.
+
+use crate::{
+    crossterm::style::{Color, ContentStyle},
+    grapheme::{StyledGrapheme, StyledGraphemes},
+    style::StyleBuilder,
+};
+
+/// The position indicators of a scrolling pane,
+/// e.g. the items of a listbox or the lines of a JSON viewer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Indicator {
+    /// Shows a scrollbar in the rightmost column.
+    pub scrollbar: bool,
+    /// Shows the position of the cursor, e.g. `12/340`, below the items.
+    pub footer: bool,
+    /// Style for the scrollbar and the footer.
+    pub style: ContentStyle,
+}
+
+impl Default for Indicator {
+    fn default() -> Self {
+        Self {
+            scrollbar: false,
+            footer: false,
+            style: StyleBuilder::new().fgc(Color::DarkGrey).build(),
+        }
+    }
+}
+
+impl Indicator {
+    /// Returns the width and height left for the items.
+    /// The footer is left out when there is only room for one row.
+    pub(crate) fn content_size(&self, width: u16, height: usize) -> (u16, usize) {
+        (
+            if self.scrollbar {
+                width.saturating_sub(1)
+            } else {
+                width
+            },
+            if self.footer && height > 1 {
+                height - 1
+            } else {
+                height
+            },
+        )
+    }
+
+    /// Adds the scrollbar and the footer to the rows of the items,
+    /// which start with the item at `position` out of `total`.
+    ///
+    /// `width` and `height` are the size of the whole pane,
+    /// and the rows are cut to the height left for the items.
+    /// The pane must be created with the height it is drawn in,
+    /// e.g. without the rows of a title above it, for the footer to stay visible.
+    pub(crate) fn decorate(
+        &self,
+        mut rows: Vec<StyledGraphemes>,
+        width: u16,
+        height: usize,
+        position: usize,
+        total: usize,
+    ) -> Vec<StyledGraphemes> {
+        let (content_width, content_height) = self.content_size(width, height);
+        rows.truncate(content_height);
+
+        if self.scrollbar && !rows.is_empty() && total > 0 {
+            let track = rows.len();
+            // The rows may wrap, so the number of visible items is an estimate.
+            let visible = rows.len().min(total - position);
+            let thumb = (track * visible).div_ceil(total).clamp(1, track);
+            let start = if position + visible >= total {
+                track - thumb
+            } else {
+                (track * position / total).min(track - thumb)
+            };
+            for (i, row) in rows.iter_mut().enumerate() {
+                let padding = (content_width as usize).saturating_sub(row.widths());
+                row.extend(StyledGraphemes::from(" ".repeat(padding)).iter().cloned());
+                let symbol = if (start..start + thumb).contains(&i) {
+                    '┃'
+                } else {
+                    '│'
+                };
+                row.push_back(StyledGrapheme::new(symbol, self.style));
+            }
+        }
+
+        if self.footer && height > 1 {
+            let current = if total == 0 { 0 } else { position + 1 };
+            rows.push(StyledGraphemes::from_str(
+                format!("{}/{}", current, total),
+                self.style,
+            ));
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod decorate {
+        use super::*;
+
+        fn rows(rows: &[&str]) -> Vec<StyledGraphemes> {
+            rows.iter().map(StyledGraphemes::from).collect()
+        }
+
+        fn decorate(indicator: Indicator, position: usize, total: usize) -> Vec<String> {
+            indicator
+                .decorate(rows(&["a", "b", "c", "d"]), 3, 5, position, total)
+                .iter()
+                .map(|row| row.to_string())
+                .collect()
+        }
+
+        #[test]
+        fn test_scrollbar() {
+            let indicator = Indicator {
+                scrollbar: true,
+                ..Default::default()
+            };
+            assert_eq!(vec!["a ┃", "b ┃", "c │", "d │"], decorate(indicator, 0, 8));
+            assert_eq!(vec!["a │", "b │", "c ┃", "d ┃"], decorate(indicator, 4, 8));
+            assert_eq!(vec!["a ┃", "b ┃", "c ┃", "d ┃"], decorate(indicator, 0, 4));
+        }
+
+        #[test]
+        fn test_footer() {
+            let indicator = Indicator {
+                footer: true,
+                ..Default::default()
+            };
+            assert_eq!(vec!["a", "b", "c", "d", "3/8"], decorate(indicator, 2, 8));
+
+            let both = Indicator {
+                scrollbar: true,
+                footer: true,
+                ..Default::default()
+            };
+            assert_eq!(
+                vec!["a │", "b │", "c ┃", "d ┃", "5/8"],
+                decorate(both, 4, 8)
+            );
+
+            // A single row shows the item rather than the footer.
+            assert_eq!(
+                vec!["a"],
+                indicator
+                    .decorate(rows(&["a", "b"]), 3, 1, 0, 2)
+                    .iter()
+                    .map(|row| row.to_string())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! Arranges panes with size constraints, vertically or side by side.
+//!
+//! A [`Layout`] combines the panes of its children into a single pane,
+//! so it can be returned from `Renderer::create_panes` like any other pane.
+//! For example, a list with a preview to its right:
+//!
+//! ```ignore
+//! fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+//!     vec![
+//!         self.title_snapshot.create_pane(width, height),
+//!         Layout::horizontal()
+//!             .pane(Constraint::Fill, &self.listbox_snapshot)
+//!             .pane(Constraint::Fill, &self.preview_snapshot)
+//!             .create_pane(width, height),
+//!     ]
+//! }
+//! ```
+
+use crate::{grapheme::StyledGraphemes, pane::Pane, PaneFactory};
+
+/// A constraint on the size of a pane within a [`Layout`],
+/// i.e. its height in a vertical layout and its width in a horizontal one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    /// Exactly the given size, padded or truncated.
+    Fixed(usize),
+    /// At least the given size, and more if the content needs and space allows.
+    Min(usize),
+    /// At most the given size.
+    Max(usize),
+    /// The space left by the others, shared equally among the `Fill` panes.
+    /// In a vertical layout, the pane is not padded beyond its content
+    /// so that an inline prompt does not take up the whole terminal.
+    Fill,
+}
+
+/// The direction in which a [`Layout`] arranges its children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From top to bottom.
+    Vertical,
+    /// From left to right.
+    Horizontal,
+}
+
+/// Arranges the panes of its children according to their constraints.
+pub struct Layout<'a> {
+    direction: Direction,
+    children: Vec<(Constraint, Box<dyn PaneFactory + 'a>)>,
+}
+
+impl<'a> Layout<'a> {
+    /// Creates an empty layout arranging its children in the given direction.
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            children: vec![],
+        }
+    }
+
+    /// Creates an empty layout arranging its children from top to bottom.
+    pub fn vertical() -> Self {
+        Self::new(Direction::Vertical)
+    }
+
+    /// Creates an empty layout arranging its children from left to right.
+    pub fn horizontal() -> Self {
+        Self::new(Direction::Horizontal)
+    }
+
+    /// Appends a child with the given constraint.
+    /// The child can be any `PaneFactory`, including a reference to one or a nested layout.
+    pub fn pane<P: PaneFactory + 'a>(mut self, constraint: Constraint, pane: P) -> Self {
+        self.children.push((constraint, Box::new(pane)));
+        self
+    }
+
+    fn create_vertical_pane(&self, width: u16, height: u16) -> Pane {
+        let demands = self
+            .children
+            .iter()
+            .map(|(_, child)| child.create_pane(width, height).visible_row_count())
+            .collect::<Vec<_>>();
+        let constraints = self.children.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+        let heights = allocate(&constraints, &demands, height as usize, true);
+
+        let mut rows = Vec::new();
+        let mut items = Vec::new();
+        let mut panes = Vec::new();
+        // Clicks are counted from the content of the first child showing items.
+        let mut indent = None;
+        for ((constraint, child), size) in self.children.iter().zip(heights) {
+            if size == 0 {
+                continue;
+            }
+            let pane = child.create_pane(width, size as u16);
+            let mut extracted = pane.extract(size);
+            let mut extracted_items = pane.extracted_items(size);
+            let cursor = pane
+                .extracted_cursor(size)
+                .map(|(row, column)| (rows.len() + row, column));
+            if matches!(constraint, Constraint::Fixed(_) | Constraint::Min(_)) {
+                extracted.resize(size, StyledGraphemes::default());
+            }
+            extracted_items.resize(extracted.len(), None);
+            if indent.is_none() && extracted_items.iter().any(Option::is_some) {
+                indent = Some(pane.indent());
+            }
+            rows.extend(extracted);
+            items.extend(extracted_items);
+            panes.push((pane, cursor));
+        }
+        merge_attributes(
+            Pane::new(rows, 0)
+                .with_items(items)
+                .with_indent(indent.unwrap_or_default()),
+            &panes,
+        )
+    }
+
+    fn create_horizontal_pane(&self, width: u16, height: u16) -> Pane {
+        let demands = self
+            .children
+            .iter()
+            .map(|(_, child)| {
+                child
+                    .create_pane(width, height)
+                    .extract(height as usize)
+                    .iter()
+                    .map(|row| row.widths())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        let constraints = self.children.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+        let widths = allocate(&constraints, &demands, width as usize, false);
+
+        let mut left = 0;
+        // Clicks are mapped to the items of the first column showing items,
+        // counted from the left edge of its content.
+        let mut items = None;
+        let columns = self
+            .children
+            .iter()
+            .zip(&widths)
+            .filter(|(_, size)| **size > 0)
+            .map(|((_, child), size)| {
+                let pane = child.create_pane(*size as u16, height);
+                let rows = pane.extract(height as usize);
+                let cursor = pane
+                    .extracted_cursor(height as usize)
+                    .map(|(row, column)| (row, left + column));
+                let extracted_items = pane.extracted_items(height as usize);
+                if items.is_none() && extracted_items.iter().any(Option::is_some) {
+                    items = Some((extracted_items, left + pane.indent()));
+                }
+                left += size;
+                ((pane, cursor), rows, *size)
+            })
+            .collect::<Vec<_>>();
+
+        let row_count = columns
+            .iter()
+            .map(|(_, rows, _)| rows.len())
+            .max()
+            .unwrap_or(0);
+        let rows = (0..row_count)
+            .map(|i| {
+                let mut row = StyledGraphemes::default();
+                for (j, (_, rows, size)) in columns.iter().enumerate() {
+                    let cell = rows.get(i).cloned().unwrap_or_default();
+                    let padding = size.saturating_sub(cell.widths());
+                    row.extend(cell.iter().cloned());
+                    // The last column needs no padding on its right.
+                    if j + 1 < columns.len() {
+                        row.extend(StyledGraphemes::from(" ".repeat(padding)).iter().cloned());
+                    }
+                }
+                row
+            })
+            .collect();
+
+        let panes = columns
+            .into_iter()
+            .map(|(pane, _, _)| pane)
+            .collect::<Vec<_>>();
+        let (items, indent) = items.unwrap_or_default();
+        merge_attributes(
+            Pane::new(rows, 0).with_items(items).with_indent(indent),
+            &panes,
+        )
+    }
+}
+
+impl PaneFactory for Layout<'_> {
+    fn create_pane(&self, width: u16, height: u16) -> Pane {
+        match self.direction {
+            Direction::Vertical => self.create_vertical_pane(width, height),
+            Direction::Horizontal => self.create_horizontal_pane(width, height),
+        }
+    }
+}
+
+/// Gives the combined pane the highest priority and the focus of its children,
+/// and the caret of the focused child or else of the first child having one,
+/// along with its cursor style.
+///
+/// Each child comes with the location of its caret within the combined pane, if any.
+pub(crate) fn merge_attributes(
+    mut pane: Pane,
+    children: &[(Pane, Option<(usize, usize)>)],
+) -> Pane {
+    if let Some(priority) = children.iter().map(|(child, _)| child.priority()).max() {
+        pane = pane.with_priority(priority);
+    }
+    if children.iter().any(|(child, _)| child.is_focused()) {
+        pane = pane.with_focus();
+    }
+    let caret = children
+        .iter()
+        .find(|(child, cursor)| child.is_focused() && cursor.is_some())
+        .or_else(|| children.iter().find(|(_, cursor)| cursor.is_some()));
+    if let Some((child, Some((row, column)))) = caret {
+        pane = pane.with_cursor(*row, *column);
+        if let Some(style) = child.cursor_style() {
+            pane = pane.with_cursor_style(style);
+        }
+    }
+    pane
+}
+
+/// Allocates the available space to the constraints,
+/// given the space that each pane would need for its whole content.
+///
+/// The minimum sizes (`Fixed` and `Min`) are satisfied first in order,
+/// then the `Min` and `Max` panes grow up to their needs in order,
+/// and the rest is shared equally among the `Fill` panes.
+/// With `fit_fills`, a `Fill` pane gets no more than it needs,
+/// leaving the rest to the other `Fill` panes.
+fn allocate(
+    constraints: &[Constraint],
+    demands: &[usize],
+    available: usize,
+    fit_fills: bool,
+) -> Vec<usize> {
+    let mut remaining = available;
+    let mut sizes = constraints
+        .iter()
+        .map(|constraint| {
+            let base = match constraint {
+                Constraint::Fixed(n) | Constraint::Min(n) => (*n).min(remaining),
+                Constraint::Max(_) | Constraint::Fill => 0,
+            };
+            remaining -= base;
+            base
+        })
+        .collect::<Vec<_>>();
+
+    for (i, constraint) in constraints.iter().enumerate() {
+        let wanted = match constraint {
+            Constraint::Min(n) => demands[i].saturating_sub(*n),
+            Constraint::Max(n) => demands[i].min(*n),
+            Constraint::Fixed(_) | Constraint::Fill => 0,
+        };
+        let extra = wanted.min(remaining);
+        sizes[i] += extra;
+        remaining -= extra;
+    }
+
+    let mut fills = constraints
+        .iter()
+        .enumerate()
+        .filter(|(_, constraint)| **constraint == Constraint::Fill)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    // Hand out the space to the panes needing less first,
+    // so that what they leave over goes to the others.
+    if fit_fills {
+        fills.sort_by_key(|&i| demands[i]);
+    }
+    for (k, &i) in fills.iter().enumerate() {
+        let share = remaining / (fills.len() - k);
+        sizes[i] = if fit_fills {
+            demands[i].min(share)
+        } else {
+            share
+        };
+        remaining -= sizes[i];
+    }
+    sizes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::text;
+
+    mod allocate {
+        use super::*;
+
+        #[test]
+        fn test() {
+            use Constraint::*;
+
+            assert_eq!(
+                vec![2, 3, 5],
+                allocate(&[Fixed(2), Min(3), Fill], &[1, 1, 9], 10, true)
+            );
+            assert_eq!(
+                vec![2, 4, 4],
+                allocate(&[Fixed(2), Fill, Fill], &[9, 9, 9], 10, true)
+            );
+            assert_eq!(
+                vec![1, 2, 7],
+                allocate(&[Max(1), Fill, Fill], &[5, 2, 9], 10, true)
+            );
+            assert_eq!(
+                vec![3, 1, 0],
+                allocate(&[Fixed(3), Min(2), Fill], &[3, 5, 5], 4, true)
+            );
+            // Without fitting, the `Fill` panes share the space regardless of their content.
+            assert_eq!(
+                vec![1, 4, 5],
+                allocate(&[Max(1), Fill, Fill], &[5, 2, 9], 10, false)
+            );
+        }
+    }
+
+    mod create_pane {
+        use super::*;
+
+        use crate::crossterm::style::ContentStyle;
+
+        fn text(text: &str) -> text::State {
+            text::State {
+                text: text.to_string(),
+                style: ContentStyle::default(),
+            }
+        }
+
+        #[test]
+        fn test_vertical() {
+            let pane = Layout::vertical()
+                .pane(Constraint::Fixed(2), text("a"))
+                .pane(Constraint::Max(1), text("bbbbbbbb"))
+                .pane(Constraint::Fill, text("c"))
+                .create_pane(4, 10);
+            assert_eq!(
+                vec!["a", "", "bbbb", "c"],
+                pane.extract(10)
+                    .iter()
+                    .map(|row| row.to_string())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_horizontal() {
+            let left = text("abcde");
+            let pane = Layout::horizontal()
+                .pane(Constraint::Fixed(3), &left)
+                .pane(Constraint::Max(1), text("|"))
+                .pane(Constraint::Fill, text("xy"))
+                .create_pane(10, 10);
+            assert_eq!(
+                vec!["abc|xy", "de  "],
+                pane.extract(10)
+                    .iter()
+                    .map(|row| row.to_string())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_horizontal_fill() {
+            let pane = Layout::horizontal()
+                .pane(Constraint::Fill, text("ab"))
+                .pane(Constraint::Fixed(1), text("|"))
+                .pane(Constraint::Fill, text("xy"))
+                .create_pane(9, 10);
+            // The first column keeps its share however short its content is.
+            assert_eq!(
+                vec!["ab  |xy"],
+                pane.extract(10)
+                    .iter()
+                    .map(|row| row.to_string())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
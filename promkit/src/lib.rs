@@ -34,8 +34,12 @@
 //!   - Theme for designing the appearance of the prompt.
 //!     - e.g. cursor, text
 //!   - Validation for user input and error message construction.
-//! - Mouse support (partially)
+//! - Async support (`async` feature)
+//!   - `Prompt::run_async` awaits events from crossterm's `EventStream`
+//! - Mouse support (`Prompt::enable_mouse`)
 //!   - Allows scrolling through lists with the mouse wheel
+//!   - Selects list items by click, toggles checkboxes by clicking their marks,
+//!     and submits by double-click
 //!
 //! ## Examples/Demos
 //!
@@ -127,16 +131,29 @@ pub use serde_json;
 
 mod core;
 pub use core::*;
+mod error;
+pub use error::PromptError;
+pub mod block;
+pub mod event_source;
 pub mod grapheme;
+pub mod indicator;
+pub mod layout;
 pub mod pane;
+mod panic_hook;
 pub mod preset;
 pub mod style;
 pub mod suggest;
 pub mod switch;
 pub mod terminal;
+pub mod testing;
+pub mod update;
 pub mod validate;
 
-use std::io;
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     crossterm::{
@@ -145,10 +162,17 @@ use crate::{
         execute,
         terminal::{disable_raw_mode, enable_raw_mode},
     },
+    event_source::{CrosstermEventSource, EventSource},
+    grapheme::StyledGraphemes,
     pane::Pane,
-    terminal::Terminal,
+    panic_hook::PanicHook,
+    terminal::{Backend, Click, Terminal},
+    update::{Update, Updater},
 };
 
+/// The interval at which a running prompt checks for updates sent through an [`Updater`].
+const UPDATE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// Represents the signal to control the flow of a prompt.
 ///
 /// This enum is used to indicate whether a prompt should continue running
@@ -161,6 +185,19 @@ pub enum PromptSignal {
     Quit,
 }
 
+/// Determines what remains on the screen after a prompt finishes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FinalFrame {
+    /// Keeps the final state of the prompt as is.
+    #[default]
+    Keep,
+    /// Erases the region of the prompt.
+    Erase,
+    /// Replaces the region of the prompt with the one-line summary
+    /// provided by [`Finalizer::summary`].
+    Summary,
+}
+
 pub trait Finalizer {
     /// The type of the result produced by the renderer.
     type Return;
@@ -175,6 +212,27 @@ pub trait Finalizer {
     /// Returns a `Result` containing the final result of the prompt. The type of the result
     /// is defined by the `Return` associated type.
     fn finalize(&mut self) -> anyhow::Result<Self::Return>;
+
+    /// Summarizes the result in a single line, e.g. `✔ Pick a fruit: apple`.
+    ///
+    /// The summary replaces the prompt on the screen
+    /// when the prompt finishes with [`FinalFrame::Summary`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no summary is available, which is the default;
+    /// the final frame is then kept as is.
+    fn summary(&self, _result: &Self::Return) -> Option<StyledGraphemes> {
+        None
+    }
+
+    /// Restores a result produced by `finalize` as the current answer,
+    /// e.g. when going back to an answered step of a
+    /// [`Wizard`](crate::preset::wizard::Wizard) to edit it.
+    ///
+    /// Renderers that keep their state after `finalize` need nothing to do,
+    /// which is the default.
+    fn restore(&mut self, _result: Self::Return) {}
 }
 
 /// A trait for rendering components within a prompt.
@@ -215,6 +273,72 @@ pub trait Renderer: Finalizer {
     /// that the prompt should continue running, while `PromptSignal::Quit` indicates that
     /// the prompt should terminate its execution.
     fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal>;
+
+    /// Updates the state on a timer, without any user input.
+    ///
+    /// This method is called every tick interval set by [`Prompt::tick_interval`],
+    /// and the panes are redrawn after each call. It allows the renderer to show
+    /// live contents such as a spinner, a countdown or a list filled in while loading.
+    /// By default it does nothing.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `PromptSignal` in the same way as `evaluate`.
+    fn tick(&mut self) -> anyhow::Result<PromptSignal> {
+        Ok(PromptSignal::Continue)
+    }
+
+    /// Evaluates a click of the left mouse button on one of the panes,
+    /// in place of `evaluate` for the mouse event.
+    ///
+    /// Clicks are only reported if mouse capture is enabled by [`Prompt::enable_mouse`],
+    /// and only on the rows drawn from the panes returned by `create_panes`;
+    /// other mouse events, such as scrolling with the wheel, are passed to `evaluate`.
+    /// By default a click does nothing.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `PromptSignal` in the same way as `evaluate`.
+    fn evaluate_click(&mut self, _click: &Click) -> anyhow::Result<PromptSignal> {
+        Ok(PromptSignal::Continue)
+    }
+
+    /// Checks if the renderer handles <kbd>Tab</kbd> itself in its current state,
+    /// e.g. to complete an input, rather than leaving it to move the focus
+    /// when it is placed in a [`preset::container::Container`].
+    /// By default <kbd>Tab</kbd> is left to the container.
+    fn captures_tab(&self) -> bool {
+        false
+    }
+
+    /// Describes the prompt as plain text for the line-oriented fallback.
+    ///
+    /// When there is no terminal to run on (e.g. in CI or in a pipeline without a controlling terminal),
+    /// [`Prompt::run`] prints this text, reads one line from stdin
+    /// and passes it to `evaluate_line`, until the renderer quits.
+    /// The text should end with the prefix that the answer follows, without a newline.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if the renderer does not support the fallback, which is the default;
+    /// the prompt then runs on the terminal as usual.
+    fn line_prompt(&self) -> Option<String> {
+        None
+    }
+
+    /// Evaluates a line of input read in the line-oriented fallback.
+    ///
+    /// # Parameters
+    ///
+    /// * `line`: The line read from stdin, without the line terminator.
+    ///
+    /// # Returns
+    ///
+    /// Returns `PromptSignal::Quit` if the line is accepted as the answer,
+    /// or `PromptSignal::Continue` to ask for another line (e.g. if the line is invalid).
+    fn evaluate_line(&mut self, _line: &str) -> anyhow::Result<PromptSignal> {
+        Err(PromptError::NotATty.into())
+    }
 }
 
 /// Represents a customizable prompt that can handle user input and produce a result.
@@ -223,65 +347,844 @@ pub trait Renderer: Finalizer {
 /// event handling, and result production for a prompt.
 pub struct Prompt<T: Renderer> {
     pub renderer: T,
+    /// The backend that the prompt is rendered to (default: stdout).
+    output: Box<dyn Backend>,
+    /// The source that the prompt reads events from (default: crossterm).
+    event_source: Box<dyn EventSource>,
+    /// The interval at which `Renderer::tick` is called, if any.
+    tick_interval: Option<Duration>,
+    /// The channel of updates, created when an `Updater` is first requested.
+    updates: Option<update::Channel<T>>,
+    /// Whether the prompt is rendered to the alternate screen.
+    full_screen: bool,
+    /// Whether the alternate screen has been entered and not left yet.
+    in_alternate_screen: bool,
+    /// Whether the terminal has been set up by `run` and has to be restored.
+    in_raw_mode: bool,
+    /// Whether the region of the prompt has been erased by `FinalFrame::Erase`.
+    erased: bool,
+    /// Whether the terminal is restored by a panic hook while the prompt is running.
+    restore_on_panic: bool,
+    /// What remains on the screen after the prompt finishes.
+    final_frame: FinalFrame,
+    /// Whether the terminal cursor is shown at the caret, e.g. of a text editor.
+    show_cursor: bool,
+    /// Whether mouse events are captured.
+    mouse: bool,
 }
 
 impl<T: Renderer> Drop for Prompt<T> {
     fn drop(&mut self) {
+        // Nothing to restore if the prompt has only run in the line-oriented fallback.
+        if !self.in_raw_mode {
+            return;
+        }
+        Self::leave_alternate_screen(&mut self.output, &mut self.in_alternate_screen).ok();
         execute!(
-            io::stdout(),
+            self.output,
             cursor::Show,
             event::DisableMouseCapture,
-            cursor::MoveToNextLine(1),
+            event::DisableBracketedPaste,
         )
         .ok();
+        // The cursor is already where the erased prompt began,
+        // and where the command left it after leaving the alternate screen.
+        if !self.erased && !self.full_screen {
+            execute!(self.output, cursor::MoveToNextLine(1)).ok();
+        }
         disable_raw_mode().ok();
     }
 }
 
 impl<T: Renderer> Prompt<T> {
+    /// Creates a new `Prompt` rendering the given renderer to stdout.
+    pub fn new(renderer: T) -> Self {
+        Self {
+            renderer,
+            output: Box::new(io::stdout()),
+            event_source: Box::new(CrosstermEventSource),
+            tick_interval: None,
+            updates: None,
+            full_screen: false,
+            in_alternate_screen: false,
+            in_raw_mode: false,
+            erased: false,
+            restore_on_panic: false,
+            final_frame: FinalFrame::default(),
+            show_cursor: false,
+            mouse: false,
+        }
+    }
+
+    /// Sets the backend that the prompt is rendered to.
+    ///
+    /// This allows the UI to be drawn e.g. to stderr
+    /// so that stdout can be piped into other programs.
+    pub fn with_output<B: Backend + 'static>(mut self, output: B) -> Self {
+        self.output = Box::new(output);
+        self
+    }
+
+    /// Sets the source that the prompt reads events from.
+    ///
+    /// This allows the prompt to be driven by something other than the terminal,
+    /// e.g. a [`ChannelEventSource`](event_source::ChannelEventSource) fed by another thread.
+    ///
+    /// The source is only used by [`Prompt::run`];
+    /// `run_async` always awaits crossterm's `EventStream`.
+    pub fn with_event_source<S: EventSource + 'static>(mut self, source: S) -> Self {
+        self.event_source = Box::new(source);
+        self
+    }
+
+    /// Sets the interval at which `Renderer::tick` is called
+    /// to let the renderer redraw without user input.
+    pub fn tick_interval(mut self, interval: Duration) -> Self {
+        self.tick_interval = Some(interval);
+        self
+    }
+
+    /// Renders the prompt to the alternate screen using the whole terminal,
+    /// instead of inline below the cursor.
+    ///
+    /// The original screen, including its scrollback, is left untouched
+    /// and restored when the prompt finishes or is dropped.
+    /// This suits viewers of large contents such as the JSON viewer or the tree browser.
+    pub fn full_screen(mut self) -> Self {
+        self.full_screen = true;
+        self
+    }
+
+    /// Sets what remains on the screen after the prompt finishes (default: [`FinalFrame::Keep`]).
+    pub fn final_frame(mut self, final_frame: FinalFrame) -> Self {
+        self.final_frame = final_frame;
+        self
+    }
+
+    /// Installs a panic hook restoring the terminal while the prompt is running.
+    ///
+    /// Without it, a panic in user code such as a custom keymap or filter
+    /// prints its message in raw mode and may leave the cursor hidden
+    /// until the `Prompt` is dropped during unwinding.
+    /// The hook disables raw mode, shows the cursor, disables mouse capture
+    /// and leaves the alternate screen if used, and then chains to the previous hook,
+    /// which is reinstated when `run` returns.
+    pub fn restore_on_panic(mut self) -> Self {
+        self.restore_on_panic = true;
+        self
+    }
+
+    /// Shows the terminal cursor at the caret reported by the renderer
+    /// (see [`Pane::with_cursor`]) instead of hiding it.
+    ///
+    /// This lets input methods (e.g. for Japanese or Chinese) and screen readers
+    /// follow the position of the input.
+    pub fn show_cursor(mut self) -> Self {
+        self.show_cursor = true;
+        self
+    }
+
+    /// Captures mouse events, letting the renderer handle clicks
+    /// (see [`Renderer::evaluate_click`]) and scrolling with the wheel.
+    ///
+    /// While the mouse is captured, the terminal does not select text on drag,
+    /// which most terminals still allow with <kbd>Shift</kbd> held.
+    pub fn enable_mouse(mut self) -> Self {
+        self.mouse = true;
+        self
+    }
+
+    /// Returns a handle through which other threads can update the renderer while the prompt runs.
+    ///
+    /// The updates are applied between events, followed by a redraw.
+    /// Once an updater has been requested, the prompt checks for updates
+    /// at a short interval even while no events arrive.
+    pub fn updater(&mut self) -> Updater<T> {
+        let (sender, _) = self.updates.get_or_insert_with(mpsc::channel);
+        Updater::new(sender.clone())
+    }
+
     /// Runs the prompt, handling events and producing a result.
     ///
     /// This method initializes the terminal, and enters a loop
     /// to handle events until a quit signal is received.
     /// After exiting the loop, it produces and returns the result.
     ///
+    /// If stdin is not a terminal and the renderer supports it (see [`Renderer::line_prompt`]),
+    /// the prompt falls back to a line-oriented protocol instead:
+    /// the prompt is printed as plain text and the answer is read as a line from stdin,
+    /// e.g. `printf 'y\n' | prog` answers a confirm prompt even when run from a terminal.
+    ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the produced result or an error.
-    pub fn run(&mut self) -> anyhow::Result<T::Return> {
-        enable_raw_mode()?;
-        execute!(io::stdout(), cursor::Hide)?;
+    /// Returns a `Result` containing the produced result or a [`PromptError`],
+    /// which tells e.g. an interrupt by the user apart from an I/O failure.
+    pub fn run(&mut self) -> Result<T::Return, PromptError> {
+        if !io::stdin().is_terminal() && self.renderer.line_prompt().is_some() {
+            return Self::run_lines(&mut self.renderer, io::stdin().lock(), &mut self.output)
+                .map_err(PromptError::from);
+        }
+        self.run_blocking().map_err(PromptError::from)
+    }
 
-        let size = crossterm::terminal::size()?;
-        let panes = self.renderer.create_panes(size.0, size.1);
-        let mut terminal = Terminal::start_session(&panes)?;
-        terminal.draw(&panes)?;
+    fn run_blocking(&mut self) -> anyhow::Result<T::Return> {
+        let _panic_hook = self
+            .restore_on_panic
+            .then(|| PanicHook::install(self.full_screen));
+        Self::enter_raw_mode()?;
+        self.in_raw_mode = true;
+        self.erased = false;
+        self.in_alternate_screen = self.full_screen;
+        let mut terminal = Self::start(
+            &self.renderer,
+            &mut self.output,
+            self.full_screen,
+            self.show_cursor,
+            self.mouse,
+        )?;
+        let mut last_tick = Instant::now();
 
         loop {
-            let ev = event::read()?;
-
-            match &ev {
-                Event::Resize(_, _) => {
-                    terminal.position = (0, 0);
-                    crossterm::execute!(
-                        io::stdout(),
-                        crossterm::terminal::Clear(crossterm::terminal::ClearType::Purge),
-                    )?;
-                }
-                _ => {
-                    if self.renderer.evaluate(&ev)? == PromptSignal::Quit {
-                        // Renderer has a possibility to disable the cursor color to indicate termination,
-                        // and so ensure to display the state of Renderer at the end.
-                        terminal.draw(&self.renderer.create_panes(size.0, size.1))?;
-                        break;
+            if let Some((_, receiver)) = &self.updates {
+                Self::update(&mut self.renderer, &mut terminal, receiver)?;
+            }
+
+            // Wait for an event only until the next tick or the next check for updates.
+            let timeout = [
+                self.tick_interval
+                    .map(|interval| interval.saturating_sub(last_tick.elapsed())),
+                self.updates.as_ref().map(|_| UPDATE_POLL_INTERVAL),
+            ]
+            .into_iter()
+            .flatten()
+            .min();
+            if let Some(timeout) = timeout {
+                // Tick even while events keep arriving, so that the renderer is updated regularly.
+                if timeout.is_zero() || !self.event_source.poll(timeout)? {
+                    if self
+                        .tick_interval
+                        .is_some_and(|interval| last_tick.elapsed() >= interval)
+                    {
+                        last_tick = Instant::now();
+                        if Self::tick(&mut self.renderer, &mut terminal)? == PromptSignal::Quit {
+                            break;
+                        }
                     }
+                    continue;
+                }
+            }
+
+            let ev = self.event_source.read()?;
+            if Self::handle(&mut self.renderer, &mut terminal, &ev)? == PromptSignal::Quit {
+                break;
+            }
+        }
+
+        let ret = self.renderer.finalize()?;
+        Self::conclude(&self.renderer, &mut terminal, self.final_frame, &ret)?;
+        self.erased = self.final_frame == FinalFrame::Erase;
+        Self::leave_alternate_screen(&mut self.output, &mut self.in_alternate_screen)?;
+        Ok(ret)
+    }
+
+    /// Runs the prompt asynchronously, awaiting events from crossterm's `EventStream`.
+    ///
+    /// This behaves in the same way as [`Prompt::run`] except that
+    /// the events are awaited instead of blocking the thread,
+    /// so the prompt can be raced against other futures (e.g. with `tokio::select!`).
+    /// Dropping the returned future cancels the prompt;
+    /// the terminal is restored when the `Prompt` itself is dropped.
+    /// Note that the event source set by [`Prompt::with_event_source`] is not used.
+    #[cfg(feature = "async")]
+    pub async fn run_async(&mut self) -> Result<T::Return, PromptError> {
+        self.run_stream().await.map_err(PromptError::from)
+    }
+
+    #[cfg(feature = "async")]
+    async fn run_stream(&mut self) -> anyhow::Result<T::Return> {
+        use futures_util::{
+            future::{self, Either},
+            stream, StreamExt,
+        };
+
+        enum Timer {
+            Tick,
+            Update,
+        }
+
+        let _panic_hook = self
+            .restore_on_panic
+            .then(|| PanicHook::install(self.full_screen));
+        Self::enter_raw_mode()?;
+        self.in_raw_mode = true;
+        self.erased = false;
+        self.in_alternate_screen = self.full_screen;
+        let mut terminal = Self::start(
+            &self.renderer,
+            &mut self.output,
+            self.full_screen,
+            self.show_cursor,
+            self.mouse,
+        )?;
+        let mut events = event::EventStream::new();
+        let ticks = match self.tick_interval {
+            Some(interval) => ticker(interval).left_stream(),
+            None => stream::pending().right_stream(),
+        };
+        let polls = match self.updates {
+            Some(_) => ticker(UPDATE_POLL_INTERVAL).left_stream(),
+            None => stream::pending().right_stream(),
+        };
+        let mut timers = stream::select(ticks.map(|_| Timer::Tick), polls.map(|_| Timer::Update));
+
+        loop {
+            if let Some((_, receiver)) = &self.updates {
+                Self::update(&mut self.renderer, &mut terminal, receiver)?;
+            }
+
+            let signal = match future::select(events.next(), timers.next()).await {
+                Either::Left((ev, _)) => {
+                    let ev =
+                        ev.ok_or_else(|| anyhow::anyhow!("Event stream has been closed"))??;
+                    Self::handle(&mut self.renderer, &mut terminal, &ev)?
+                }
+                Either::Right((Some(Timer::Tick), _)) => {
+                    Self::tick(&mut self.renderer, &mut terminal)?
                 }
+                // Updates are applied at the top of the loop.
+                Either::Right(_) => PromptSignal::Continue,
+            };
+            if signal == PromptSignal::Quit {
+                break;
+            }
+        }
+
+        let ret = self.renderer.finalize()?;
+        Self::conclude(&self.renderer, &mut terminal, self.final_frame, &ret)?;
+        self.erased = self.final_frame == FinalFrame::Erase;
+        Self::leave_alternate_screen(&mut self.output, &mut self.in_alternate_screen)?;
+        Ok(ret)
+    }
+
+    /// Answers the prompt line by line, without a terminal.
+    fn run_lines<R: BufRead, W: Write>(
+        renderer: &mut T,
+        mut input: R,
+        output: &mut W,
+    ) -> anyhow::Result<T::Return> {
+        loop {
+            if let Some(text) = renderer.line_prompt() {
+                write!(output, "{}", text)?;
+                output.flush()?;
+            }
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Err(PromptError::Eof.into());
             }
+            // The answer is not echoed since the input is not a terminal.
+            writeln!(output)?;
 
-            let size = crossterm::terminal::size()?;
-            terminal.draw(&self.renderer.create_panes(size.0, size.1))?;
+            if renderer.evaluate_line(line.trim_end_matches(['\n', '\r']))? == PromptSignal::Quit {
+                break;
+            }
+        }
+        renderer.finalize()
+    }
+
+    /// Enables raw mode, which is only possible on a terminal.
+    fn enter_raw_mode() -> anyhow::Result<()> {
+        enable_raw_mode().map_err(|e| match has_terminal() {
+            true => anyhow::Error::from(e),
+            false => PromptError::NotATty.into(),
+        })
+    }
+
+    /// Initializes the terminal in raw mode and draws the initial state of the renderer.
+    fn start<W: Backend>(
+        renderer: &T,
+        mut output: W,
+        full_screen: bool,
+        show_cursor: bool,
+        mouse: bool,
+    ) -> anyhow::Result<Terminal<W>> {
+        execute!(output, cursor::Hide, event::EnableBracketedPaste)?;
+        if mouse {
+            execute!(output, event::EnableMouseCapture)?;
+        }
+        if full_screen {
+            execute!(
+                output,
+                crossterm::terminal::EnterAlternateScreen,
+                cursor::MoveTo(0, 0),
+                crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            )?;
         }
 
-        self.renderer.finalize()
+        let size = output.size()?;
+        let panes = renderer.create_panes(size.0, size.1);
+        let mut terminal = Terminal::start_session(output, &panes)?;
+        terminal.set_cursor_visible(show_cursor);
+        terminal.draw(&panes)?;
+        Ok(terminal)
+    }
+
+    /// Leaves the final frame on the screen according to the given policy.
+    fn conclude<W: Backend>(
+        renderer: &T,
+        terminal: &mut Terminal<W>,
+        final_frame: FinalFrame,
+        result: &T::Return,
+    ) -> anyhow::Result<()> {
+        match final_frame {
+            FinalFrame::Keep => terminal.park_cursor(),
+            FinalFrame::Erase => terminal.erase(),
+            FinalFrame::Summary => match renderer.summary(result) {
+                Some(summary) => {
+                    let size = terminal.backend().size()?;
+                    terminal.draw(&[Pane::new(
+                        summary.matrixify(size.0 as usize, size.1 as usize, 0).0,
+                        0,
+                    )])
+                }
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Leaves the alternate screen if it has been entered, restoring the original screen.
+    fn leave_alternate_screen<W: Write>(
+        output: &mut W,
+        in_alternate_screen: &mut bool,
+    ) -> anyhow::Result<()> {
+        if *in_alternate_screen {
+            execute!(output, crossterm::terminal::LeaveAlternateScreen)?;
+            *in_alternate_screen = false;
+        }
+        Ok(())
+    }
+
+    /// Passes the event to the renderer and redraws it.
+    fn handle<W: Backend>(
+        renderer: &mut T,
+        terminal: &mut Terminal<W>,
+        ev: &Event,
+    ) -> anyhow::Result<PromptSignal> {
+        let mut signal = PromptSignal::Continue;
+
+        match ev {
+            Event::Resize(_, _) => terminal.reposition()?,
+            _ => {
+                let click = match ev {
+                    Event::Mouse(mouse) => terminal.click(mouse),
+                    _ => None,
+                };
+                let evaluated = match click {
+                    Some(click) => renderer.evaluate_click(&click),
+                    None => renderer.evaluate(ev),
+                };
+                signal = evaluated.inspect_err(|_| {
+                    // Leave the rows intact for whatever follows the prompt.
+                    terminal.park_cursor().ok();
+                })?
+            }
+        }
+
+        // Redraw even when quitting, since the renderer has a possibility to disable
+        // the cursor color to indicate termination, and so display its state at the end.
+        let size = terminal.backend().size()?;
+        terminal.draw(&renderer.create_panes(size.0, size.1))?;
+        Ok(signal)
+    }
+
+    /// Applies the pending updates to the renderer and redraws it if there were any.
+    fn update<W: Backend>(
+        renderer: &mut T,
+        terminal: &mut Terminal<W>,
+        receiver: &mpsc::Receiver<Update<T>>,
+    ) -> anyhow::Result<()> {
+        let mut updated = false;
+        while let Ok(update) = receiver.try_recv() {
+            update(renderer);
+            updated = true;
+        }
+
+        if updated {
+            let size = terminal.backend().size()?;
+            terminal.draw(&renderer.create_panes(size.0, size.1))?;
+        }
+        Ok(())
+    }
+
+    /// Lets the renderer update its state on a timer and redraws it.
+    fn tick<W: Backend>(
+        renderer: &mut T,
+        terminal: &mut Terminal<W>,
+    ) -> anyhow::Result<PromptSignal> {
+        let signal = renderer.tick()?;
+        let size = terminal.backend().size()?;
+        terminal.draw(&renderer.create_panes(size.0, size.1))?;
+        Ok(signal)
+    }
+}
+
+/// Returns whether the process has a terminal to run a prompt on.
+///
+/// On Unix this is the controlling terminal, which crossterm reads from
+/// even if stdin is redirected (e.g. `cmd | prog`).
+fn has_terminal() -> bool {
+    #[cfg(unix)]
+    {
+        std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .is_ok()
+    }
+    #[cfg(not(unix))]
+    {
+        io::stdin().is_terminal()
+    }
+}
+
+/// Creates a stream yielding every `interval`, independently of any async runtime.
+///
+/// The ticks are produced by a helper thread, which exits
+/// at the first tick after the stream has been dropped.
+#[cfg(feature = "async")]
+fn ticker(interval: Duration) -> futures_channel::mpsc::UnboundedReceiver<()> {
+    let (sender, receiver) = futures_channel::mpsc::unbounded();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(interval);
+        if sender.unbounded_send(()).is_err() {
+            break;
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::{grapheme::StyledGraphemes, testing::VirtualTerminal};
+
+    /// A renderer counting the ticks it has received.
+    struct Counter(usize);
+
+    impl Finalizer for Counter {
+        type Return = usize;
+
+        fn finalize(&mut self) -> anyhow::Result<Self::Return> {
+            Ok(self.0)
+        }
+    }
+
+    impl Renderer for Counter {
+        fn create_panes(&self, _width: u16, _height: u16) -> Vec<Pane> {
+            vec![Pane::new(
+                vec![StyledGraphemes::from(format!("ticks: {}", self.0))],
+                0,
+            )]
+        }
+
+        fn evaluate(&mut self, _event: &Event) -> anyhow::Result<PromptSignal> {
+            Ok(PromptSignal::Continue)
+        }
+
+        fn tick(&mut self) -> anyhow::Result<PromptSignal> {
+            self.0 += 1;
+            if self.0 == 2 {
+                return Ok(PromptSignal::Quit);
+            }
+            Ok(PromptSignal::Continue)
+        }
+    }
+
+    mod tick {
+        use super::*;
+
+        #[test]
+        fn test_redraw() {
+            let mut renderer = Counter(0);
+            let panes = renderer.create_panes(20, 5);
+            let mut terminal =
+                Terminal::start_session(VirtualTerminal::new(20, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+            assert_eq!("ticks: 0", terminal.backend().frame().rows()[0]);
+
+            assert!(Prompt::tick(&mut renderer, &mut terminal).unwrap() == PromptSignal::Continue);
+            assert_eq!("ticks: 1", terminal.backend().frame().rows()[0]);
+
+            assert!(Prompt::tick(&mut renderer, &mut terminal).unwrap() == PromptSignal::Quit);
+            assert_eq!("ticks: 2", terminal.backend().frame().rows()[0]);
+        }
+    }
+
+    mod update {
+        use super::*;
+
+        #[test]
+        fn test_apply_and_redraw() {
+            let mut renderer = Counter(0);
+            let panes = renderer.create_panes(20, 5);
+            let mut terminal =
+                Terminal::start_session(VirtualTerminal::new(20, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            let (sender, receiver) = mpsc::channel();
+            let updater = Updater::new(sender);
+            std::thread::spawn(move || {
+                updater.send(|r: &mut Counter| r.0 += 3).unwrap();
+                updater.send(|r: &mut Counter| r.0 *= 2).unwrap();
+            })
+            .join()
+            .unwrap();
+
+            Prompt::update(&mut renderer, &mut terminal, &receiver).unwrap();
+            assert_eq!(6, renderer.0);
+            assert_eq!("ticks: 6", terminal.backend().frame().rows()[0]);
+        }
+    }
+
+    mod full_screen {
+        use super::*;
+
+        #[test]
+        fn test_enter_and_leave() {
+            let mut output = VirtualTerminal::new(20, 5);
+            write!(output, "$ cmd\r\n").unwrap();
+
+            let mut renderer = Counter(0);
+            let mut terminal = Prompt::start(&renderer, output, true, false, false).unwrap();
+            let frame = terminal.backend().frame();
+            assert!(frame.alternate_screen);
+            // The prompt is drawn from the top, not below the output of the command.
+            assert_eq!(vec!["ticks: 0", "", "", "", ""], frame.rows());
+
+            Prompt::tick(&mut renderer, &mut terminal).unwrap();
+            Prompt::conclude(&renderer, &mut terminal, FinalFrame::Keep, &1).unwrap();
+            let mut in_alternate_screen = true;
+            Prompt::<Counter>::leave_alternate_screen(
+                terminal.backend_mut(),
+                &mut in_alternate_screen,
+            )
+            .unwrap();
+            assert!(!in_alternate_screen);
+            let frame = terminal.backend().frame();
+            assert!(!frame.alternate_screen);
+            assert_eq!(vec!["$ cmd", "", "", "", ""], frame.rows());
+        }
+
+        #[test]
+        fn test_drop() {
+            use std::{cell::RefCell, rc::Rc};
+
+            /// A terminal that can be inspected after the prompt owning it is dropped.
+            struct Shared(Rc<RefCell<VirtualTerminal>>);
+
+            impl Write for Shared {
+                fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                    self.0.borrow_mut().write(buf)
+                }
+
+                fn flush(&mut self) -> io::Result<()> {
+                    self.0.borrow_mut().flush()
+                }
+            }
+
+            impl Backend for Shared {
+                fn size(&self) -> anyhow::Result<(u16, u16)> {
+                    self.0.borrow().size()
+                }
+
+                fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+                    self.0.borrow_mut().position()
+                }
+            }
+
+            let output = Rc::new(RefCell::new(VirtualTerminal::new(20, 5)));
+            write!(output.borrow_mut(), "$ cmd\r\n").unwrap();
+
+            let mut prompt = Prompt::new(Counter(0))
+                .with_output(Shared(output.clone()))
+                .full_screen();
+            Prompt::start(&prompt.renderer, &mut prompt.output, true, false, false).unwrap();
+            prompt.in_raw_mode = true;
+            prompt.in_alternate_screen = true;
+            drop(prompt);
+
+            // The cursor stays on the line after the command.
+            let frame = output.borrow().frame();
+            assert!(!frame.alternate_screen);
+            assert_eq!((0, 1), frame.cursor);
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        use std::process::{Command, Stdio};
+
+        use crate::preset::confirm::Confirm;
+
+        #[test]
+        fn test_piped_stdin() {
+            // Run this test again with a pipe as stdin,
+            // which has to answer the prompt even if the tests run on a terminal.
+            if std::env::var_os("PROMKIT_TEST_PIPED_STDIN").is_some() {
+                let mut prompt = Confirm::new("Continue?").prompt().unwrap();
+                assert_eq!("y", prompt.run().unwrap());
+                return;
+            }
+            let mut child = Command::new(std::env::current_exe().unwrap())
+                .args(["--exact", "test::run::test_piped_stdin"])
+                .env("PROMKIT_TEST_PIPED_STDIN", "1")
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .spawn()
+                .unwrap();
+            child.stdin.take().unwrap().write_all(b"y\n").unwrap();
+            assert!(child.wait().unwrap().success());
+        }
+    }
+
+    mod run_lines {
+        use super::*;
+
+        use crate::preset::{checkbox::Checkbox, confirm::Confirm, listbox::Listbox};
+
+        #[test]
+        fn test_validate() {
+            let mut prompt = Confirm::new("Continue?").prompt().unwrap();
+            let mut output = Vec::new();
+            let ret = Prompt::run_lines(&mut prompt.renderer, "maybe\ny\n".as_bytes(), &mut output)
+                .unwrap();
+
+            assert_eq!("y", ret);
+            assert_eq!(
+                "Continue? (y/n) \n\
+                 Please type 'y' or 'n' as an answer\n\
+                 Continue? (y/n) \n",
+                String::from_utf8(output).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_listbox() {
+            let mut prompt = Listbox::new(["apple", "banana", "cherry"])
+                .title("Pick a fruit")
+                .prompt()
+                .unwrap();
+            let mut output = Vec::new();
+            let ret =
+                Prompt::run_lines(&mut prompt.renderer, "2\n".as_bytes(), &mut output).unwrap();
+            assert_eq!("banana", ret);
+            assert_eq!(
+                "Pick a fruit\n1) apple\n2) banana\n3) cherry\n❯ \n",
+                String::from_utf8(output).unwrap()
+            );
+
+            let ret =
+                Prompt::run_lines(&mut prompt.renderer, "cherry\n".as_bytes(), &mut io::sink())
+                    .unwrap();
+            assert_eq!("cherry", ret);
+        }
+
+        #[test]
+        fn test_checkbox() {
+            let mut prompt = Checkbox::new(["apple", "banana", "cherry"])
+                .prompt()
+                .unwrap();
+            let mut output = Vec::new();
+            let mut ret = Prompt::run_lines(
+                &mut prompt.renderer,
+                "grape\n3, apple\n".as_bytes(),
+                &mut output,
+            )
+            .unwrap();
+            ret.sort();
+            assert_eq!(vec!["apple", "cherry"], ret);
+            assert_eq!(
+                "1) apple\n2) banana\n3) cherry\n❯ \n\
+                 1) apple\n2) banana\n3) cherry\n'grape' is not one of the items\n❯ \n",
+                String::from_utf8(output).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_eof() {
+            let mut prompt = Confirm::new("Continue?").prompt().unwrap();
+            let ret =
+                Prompt::run_lines(&mut prompt.renderer, "maybe\n".as_bytes(), &mut io::sink());
+            assert!(matches!(
+                ret.map_err(PromptError::from),
+                Err(PromptError::Eof)
+            ));
+        }
+    }
+
+    mod conclude {
+        use super::*;
+
+        use crate::preset::listbox::Listbox;
+
+        fn setup() -> (
+            Prompt<preset::listbox::render::Renderer>,
+            Terminal<VirtualTerminal>,
+        ) {
+            let prompt = Listbox::new(["apple", "banana"])
+                .title("Pick a fruit")
+                .prompt()
+                .unwrap();
+            let panes = prompt.renderer.create_panes(30, 5);
+            let mut terminal =
+                Terminal::start_session(VirtualTerminal::new(30, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+            (prompt, terminal)
+        }
+
+        #[test]
+        fn test_keep() {
+            let (prompt, mut terminal) = setup();
+            let before = terminal.backend().frame().rows();
+            Prompt::conclude(
+                &prompt.renderer,
+                &mut terminal,
+                FinalFrame::Keep,
+                &String::from("apple"),
+            )
+            .unwrap();
+            assert_eq!(before, terminal.backend().frame().rows());
+        }
+
+        #[test]
+        fn test_erase() {
+            let (prompt, mut terminal) = setup();
+            Prompt::conclude(
+                &prompt.renderer,
+                &mut terminal,
+                FinalFrame::Erase,
+                &String::from("apple"),
+            )
+            .unwrap();
+            assert_eq!(vec![""; 5], terminal.backend().frame().rows());
+        }
+
+        #[test]
+        fn test_summary() {
+            let (prompt, mut terminal) = setup();
+            Prompt::conclude(
+                &prompt.renderer,
+                &mut terminal,
+                FinalFrame::Summary,
+                &String::from("apple"),
+            )
+            .unwrap();
+            assert_eq!(
+                vec!["✔ Pick a fruit: apple", "", "", "", ""],
+                terminal.backend().frame().rows()
+            );
+        }
     }
 }
@@ -1,4 +1,16 @@
-use crate::grapheme::StyledGraphemes;
+use crate::{crossterm::style::ContentStyle, grapheme::StyledGraphemes};
+
+/// The priority of a pane to stay visible
+/// when the terminal has fewer rows than there are panes.
+/// Panes with lower priorities are collapsed first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// For decorations such as titles, error messages and suggestions.
+    Low,
+    #[default]
+    Normal,
+    High,
+}
 
 #[derive(Clone)]
 pub struct Pane {
@@ -8,6 +20,20 @@ pub struct Pane {
     /// The offset from the top of the pane, used when extracting graphemes to display.
     /// This value determines the starting point for grapheme extraction, allowing for scrolling behavior.
     offset: usize,
+    /// The priority of the pane to stay visible when space is insufficient.
+    priority: Priority,
+    /// Whether the pane has the focus, which keeps it visible regardless of its priority.
+    focused: bool,
+    /// The location of the caret as `(row, column)` within the layout, if any.
+    cursor: Option<(usize, usize)>,
+    /// The style of the grapheme at the caret that fakes a cursor
+    /// while the terminal cursor is hidden.
+    cursor_style: Option<ContentStyle>,
+    /// The index of the item that each row of the layout shows, e.g. of a list.
+    /// Rows beyond its length show no item.
+    items: Vec<Option<usize>>,
+    /// The number of columns in front of the content of each row, e.g. of a border.
+    indent: usize,
 }
 
 impl Pane {
@@ -15,7 +41,71 @@ impl Pane {
     /// - `layout`: A vector of `StyledGraphemes` representing the content of the pane.
     /// - `offset`: The initial offset from the top of the pane.
     pub fn new(layout: Vec<StyledGraphemes>, offset: usize) -> Self {
-        Pane { layout, offset }
+        Pane {
+            layout,
+            offset,
+            priority: Priority::default(),
+            focused: false,
+            cursor: None,
+            cursor_style: None,
+            items: vec![],
+            indent: 0,
+        }
+    }
+
+    /// Sets the priority of the pane to stay visible when space is insufficient.
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Marks the pane as focused, so that it always stays visible.
+    pub fn with_focus(mut self) -> Self {
+        self.focused = true;
+        self
+    }
+
+    /// Sets the location of the caret as `(row, column)` within the layout,
+    /// where the terminal cursor is placed if it is shown.
+    pub fn with_cursor(mut self, row: usize, column: usize) -> Self {
+        self.cursor = Some((row, column));
+        self
+    }
+
+    /// Sets the style of the grapheme at the caret, which fakes a cursor
+    /// unless the terminal cursor is shown there (see [`Pane::extract_with_fake_cursor`]).
+    pub fn with_cursor_style(mut self, style: ContentStyle) -> Self {
+        self.cursor_style = Some(style);
+        self
+    }
+
+    /// Sets the index of the item that each row of the layout shows, e.g. of a list,
+    /// so that a click on a row can be mapped to its item (see [`Click`](crate::terminal::Click)).
+    pub fn with_items(mut self, items: Vec<Option<usize>>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Sets the number of columns in front of the content of each row, e.g. of a border,
+    /// which are not counted in the column of a click (see [`Click`](crate::terminal::Click)).
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Returns the number of columns in front of the content of each row.
+    pub fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// Returns the priority of the pane.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Checks if the pane is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
     }
 
     pub fn visible_row_count(&self) -> usize {
@@ -27,21 +117,72 @@ impl Pane {
         self.layout.is_empty()
     }
 
-    pub fn extract(&self, viewport_height: usize) -> Vec<StyledGraphemes> {
+    /// Returns the location of the caret as `(row, column)` within the layout, if any.
+    pub fn cursor(&self) -> Option<(usize, usize)> {
+        self.cursor
+    }
+
+    /// Returns the style of the grapheme at the caret that fakes a cursor, if any.
+    pub fn cursor_style(&self) -> Option<ContentStyle> {
+        self.cursor_style
+    }
+
+    /// Returns the range of rows in the layout that `extract` returns.
+    fn visible_range(&self, viewport_height: usize) -> std::ops::Range<usize> {
         let lines = self.layout.len().min(viewport_height);
         let mut start = self.offset;
         let end = self.offset + lines;
         if end > self.layout.len() {
             start = self.layout.len().saturating_sub(lines);
         }
+        start..end.min(self.layout.len())
+    }
 
+    /// Returns the location of the caret within the rows that `extract` returns,
+    /// or `None` if there is no caret or it is scrolled out of view.
+    pub fn extracted_cursor(&self, viewport_height: usize) -> Option<(usize, usize)> {
+        let (row, column) = self.cursor?;
+        let range = self.visible_range(viewport_height);
+        range.contains(&row).then(|| (row - range.start, column))
+    }
+
+    /// Returns the index of the item shown on each of the rows that `extract` returns.
+    pub fn extracted_items(&self, viewport_height: usize) -> Vec<Option<usize>> {
+        self.visible_range(viewport_height)
+            .map(|i| self.items.get(i).copied().flatten())
+            .collect()
+    }
+
+    pub fn extract(&self, viewport_height: usize) -> Vec<StyledGraphemes> {
+        let range = self.visible_range(viewport_height);
         self.layout
             .iter()
             .enumerate()
-            .filter(|(i, _)| start <= *i && *i < end)
+            .filter(|(i, _)| range.contains(i))
             .map(|(_, row)| row.clone())
             .collect::<Vec<_>>()
     }
+
+    /// Returns the rows that `extract` returns,
+    /// with the grapheme at the caret in the cursor style to fake a cursor.
+    pub fn extract_with_fake_cursor(&self, viewport_height: usize) -> Vec<StyledGraphemes> {
+        let mut rows = self.extract(viewport_height);
+        let (Some((row, column)), Some(style)) =
+            (self.extracted_cursor(viewport_height), self.cursor_style)
+        else {
+            return rows;
+        };
+        if let Some(row) = rows.get_mut(row) {
+            let mut left = 0;
+            if let Some(grapheme) = row.0.iter_mut().find(|grapheme| {
+                left += grapheme.width();
+                left > column
+            }) {
+                grapheme.apply_style(style);
+            }
+        }
+        rows
+    }
 }
 
 #[cfg(test)]
@@ -69,11 +210,83 @@ mod test {
                 Pane {
                     layout: StyledGraphemes::from("").matrixify(10, 10, 0).0,
                     offset: 0,
+                    priority: Priority::Normal,
+                    focused: false,
+                    cursor: None,
+                    cursor_style: None,
+                    items: vec![],
+                    indent: 0,
                 }
                 .is_empty()
             );
         }
     }
+    mod extracted_cursor {
+        use super::super::*;
+
+        #[test]
+        fn test() {
+            let pane = Pane::new(
+                vec![
+                    StyledGraphemes::from("aa"),
+                    StyledGraphemes::from("bb"),
+                    StyledGraphemes::from("cc"),
+                ],
+                1,
+            )
+            .with_cursor(2, 1);
+            assert_eq!(Some((1, 1)), pane.extracted_cursor(2));
+            // Scrolled out of view.
+            assert_eq!(None, pane.extracted_cursor(1));
+            assert_eq!(None, Pane::new(vec![], 0).extracted_cursor(1));
+        }
+    }
+
+    mod extract_with_fake_cursor {
+        use super::super::*;
+
+        #[test]
+        fn test() {
+            let style = ContentStyle {
+                attributes: crate::crossterm::style::Attribute::Reverse.into(),
+                ..Default::default()
+            };
+            let pane = Pane::new(
+                vec![StyledGraphemes::from("aa"), StyledGraphemes::from("あb")],
+                0,
+            )
+            .with_cursor(1, 2)
+            .with_cursor_style(style);
+            let rows = pane.extract_with_fake_cursor(2);
+            assert_eq!(pane.extract(2)[0], rows[0]);
+            assert_eq!(
+                StyledGraphemes::from("あb").apply_style_at(1, style),
+                rows[1]
+            );
+            // Without a style, the rows are left as they are.
+            let pane = Pane::new(vec![StyledGraphemes::from("aa")], 0).with_cursor(0, 1);
+            assert_eq!(pane.extract(1), pane.extract_with_fake_cursor(1));
+        }
+    }
+
+    mod extracted_items {
+        use super::super::*;
+
+        #[test]
+        fn test() {
+            let pane = Pane::new(
+                vec![
+                    StyledGraphemes::from("aa"),
+                    StyledGraphemes::from("bb"),
+                    StyledGraphemes::from("cc"),
+                ],
+                1,
+            )
+            .with_items(vec![Some(0), Some(1)]);
+            assert_eq!(vec![Some(1), None], pane.extracted_items(2));
+        }
+    }
+
     mod extract {
         use super::super::*;
 
@@ -95,6 +308,12 @@ mod test {
                         StyledGraphemes::from("ee"),
                     ],
                     offset: 0,
+                    priority: Priority::Normal,
+                    focused: false,
+                    cursor: None,
+                    cursor_style: None,
+                    items: vec![],
+                    indent: 0,
                 }
                 .extract(3)
             );
@@ -120,6 +339,12 @@ mod test {
                         StyledGraphemes::from("ee"),
                     ],
                     offset: 0,
+                    priority: Priority::Normal,
+                    focused: false,
+                    cursor: None,
+                    cursor_style: None,
+                    items: vec![],
+                    indent: 0,
                 }
                 .extract(10)
             );
@@ -139,6 +364,12 @@ mod test {
                         StyledGraphemes::from("ee"),
                     ],
                     offset: 2, // indicate `cc`
+                    priority: Priority::Normal,
+                    focused: false,
+                    cursor: None,
+                    cursor_style: None,
+                    items: vec![],
+                    indent: 0,
                 }
                 .extract(2)
             );
@@ -162,6 +393,12 @@ mod test {
                         StyledGraphemes::from("ee"),
                     ],
                     offset: 3, // indicate `dd`
+                    priority: Priority::Normal,
+                    focused: false,
+                    cursor: None,
+                    cursor_style: None,
+                    items: vec![],
+                    indent: 0,
                 }
                 .extract(3)
             );
@@ -0,0 +1,123 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    panic::{self, PanicHookInfo},
+    sync::Arc,
+};
+
+use crate::crossterm::{cursor, event, execute, terminal};
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static>;
+
+/// A panic hook restoring the terminal, installed while a prompt is running.
+///
+/// On panic, the terminal is restored before the previous hook runs,
+/// so that the panic message is printed in a usable terminal.
+/// The previous hook is put back as is when this guard is dropped.
+pub(crate) struct PanicHook {
+    /// The previous hook, shared with the installed one until it is put back.
+    previous: Option<Arc<Hook>>,
+}
+
+impl PanicHook {
+    /// Installs the hook on top of the current one.
+    ///
+    /// `alternate_screen` tells whether the prompt renders to the alternate screen,
+    /// which has to be left on panic.
+    pub fn install(alternate_screen: bool) -> Self {
+        Self::install_with(move || restore(alternate_screen))
+    }
+
+    /// Installs a hook calling `restore` and then the current hook.
+    fn install_with<F: Fn() + Send + Sync + 'static>(restore: F) -> Self {
+        let previous: Arc<Hook> = Arc::new(panic::take_hook());
+        let chained = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            chained(info);
+        }));
+        Self {
+            previous: Some(previous),
+        }
+    }
+}
+
+impl Drop for PanicHook {
+    fn drop(&mut self) {
+        // The hook cannot be replaced while panicking;
+        // it is left installed since it chains to the previous one anyway.
+        if std::thread::panicking() {
+            return;
+        }
+        let Some(previous) = self.previous.take() else {
+            return;
+        };
+        // Dropping the installed hook releases its share of the previous one.
+        drop(panic::take_hook());
+        match Arc::try_unwrap(previous) {
+            Ok(previous) => panic::set_hook(previous),
+            // Still shared, e.g. by a hook installed on top of ours and then taken by someone.
+            Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+        }
+    }
+}
+
+/// Restores the terminal as `Prompt` does when dropped, ignoring any errors.
+fn restore(alternate_screen: bool) {
+    // The hook has no access to the backend of the prompt,
+    // so write to whichever of stdout and stderr is the terminal.
+    let mut output: Box<dyn Write> = if io::stdout().is_terminal() {
+        Box::new(io::stdout())
+    } else {
+        Box::new(io::stderr())
+    };
+    terminal::disable_raw_mode().ok();
+    execute!(
+        output,
+        cursor::Show,
+        event::DisableMouseCapture,
+        event::DisableBracketedPaste,
+    )
+    .ok();
+    if alternate_screen {
+        execute!(output, terminal::LeaveAlternateScreen).ok();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    mod install {
+        use super::*;
+
+        static RESTORED: AtomicUsize = AtomicUsize::new(0);
+        static PREVIOUS: AtomicUsize = AtomicUsize::new(0);
+
+        #[test]
+        fn test_restore_and_chain() {
+            let original = panic::take_hook();
+            panic::set_hook(Box::new(|_| {
+                PREVIOUS.fetch_add(1, Ordering::SeqCst);
+            }));
+
+            for _ in 0..3 {
+                let hook = PanicHook::install_with(|| {
+                    RESTORED.fetch_add(1, Ordering::SeqCst);
+                });
+                assert!(panic::catch_unwind(|| panic!("in a prompt")).is_err());
+                drop(hook);
+            }
+            assert_eq!(3, RESTORED.load(Ordering::SeqCst));
+            assert_eq!(3, PREVIOUS.load(Ordering::SeqCst));
+
+            // The previous hook is back as is, without restoring the terminal.
+            assert!(panic::catch_unwind(|| panic!("after the prompt")).is_err());
+            assert_eq!(3, RESTORED.load(Ordering::SeqCst));
+            assert_eq!(4, PREVIOUS.load(Ordering::SeqCst));
+
+            panic::set_hook(original);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{crossterm::style::Color, grapheme::StyledGraphemes, pane::Pane, style::StyleBuilder};
+
 /// Provides a checkbox interface for multiple options selection.
 pub mod checkbox;
 
@@ -18,3 +20,41 @@ pub mod query_selector;
 pub mod tree;
 
 pub mod form;
+
+/// Composes several components into a single prompt with a movable focus.
+pub mod container;
+
+/// Asks a sequence of questions with the ability to go back to earlier ones.
+pub mod wizard;
+
+/// Builds the one-line summary of an answered prompt, e.g. `✔ Pick a fruit: apple`.
+///
+/// The question is omitted if it is empty.
+pub(crate) fn summary<Q: AsRef<str>, A: AsRef<str>>(question: Q, answer: A) -> StyledGraphemes {
+    let question = question.as_ref().trim_end();
+    [
+        StyledGraphemes::from_str("✔ ", StyleBuilder::new().fgc(Color::DarkGreen).build()),
+        StyledGraphemes::from(if question.is_empty() {
+            String::new()
+        } else if question.ends_with(['?', ':']) {
+            format!("{} ", question)
+        } else {
+            format!("{}: ", question)
+        }),
+        StyledGraphemes::from_str(answer, StyleBuilder::new().fgc(Color::DarkCyan).build()),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Returns the height that the terminal leaves for a pane drawn below the given ones,
+/// so that the pane can be created to fit, e.g. keeping a footer in view.
+pub(crate) fn height_below(height: u16, above: &[&Pane]) -> u16 {
+    let rows = above
+        .iter()
+        .map(|pane| pane.visible_row_count())
+        .sum::<usize>();
+    height
+        .saturating_sub(rows.min(u16::MAX as usize) as u16)
+        .max(1)
+}
@@ -3,6 +3,7 @@ use std::{cell::RefCell, fmt::Display};
 use crate::{
     checkbox,
     crossterm::style::{Attribute, Attributes, Color, ContentStyle},
+    indicator::Indicator,
     snapshot::Snapshot,
     style::StyleBuilder,
     switch::ActiveKeySwitcher,
@@ -46,6 +47,7 @@ impl Checkbox {
                 active_item_style: StyleBuilder::new().fgc(Color::DarkCyan).build(),
                 inactive_item_style: StyleBuilder::new().build(),
                 lines: Default::default(),
+                indicator: Default::default(),
             },
             keymap: ActiveKeySwitcher::new("default", self::keymap::default),
         }
@@ -67,6 +69,7 @@ impl Checkbox {
                 active_item_style: StyleBuilder::new().fgc(Color::DarkCyan).build(),
                 inactive_item_style: StyleBuilder::new().build(),
                 lines: Default::default(),
+                indicator: Default::default(),
             },
             keymap: ActiveKeySwitcher::new("default", self::keymap::default),
         }
@@ -114,6 +117,12 @@ impl Checkbox {
         self
     }
 
+    /// Sets the scrollbar and position footer shown with the items.
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.checkbox_state.indicator = indicator;
+        self
+    }
+
     pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
         self.keymap = self.keymap.register(key, handler);
         self
@@ -123,12 +132,41 @@ impl Checkbox {
     /// Returns a `Result` containing the `Prompt` result,
     /// which is a list of selected options.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                checkbox_snapshot: Snapshot::<checkbox::State>::new(self.checkbox_state),
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            checkbox_snapshot: Snapshot::<checkbox::State>::new(self.checkbox_state),
+            line_error: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod run {
+        use super::*;
+
+        use crate::testing::{self, click};
+
+        #[test]
+        fn test_click() {
+            let mut prompt = Checkbox::new(["a", "b", "c"])
+                .title("Pick")
+                .prompt()
+                .unwrap();
+            let events = [
+                // On the mark of `b`.
+                click(2, 2),
+                // On the text of `c`, which comes right below the cursor.
+                click(5, 2),
+                click(5, 2),
+            ];
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+            assert_eq!("❯ ☒ b", transcript.frames[1].rows()[1]);
+            assert_eq!("❯ ☐ c", transcript.frames[2].rows()[1]);
+            assert_eq!(vec![String::from("b")], transcript.result.unwrap().unwrap());
+        }
     }
 }
@@ -3,7 +3,7 @@ use crate::{
         Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent,
         MouseEventKind,
     },
-    preset, PromptSignal,
+    preset, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -17,6 +17,7 @@ pub type Keymap = fn(
 /// | :--------------------- | :-------------------------------------------
 /// | <kbd>Enter</kbd>       | Exit the interface
 /// | <kbd>Ctrl + C</kbd>    | Interrupt the current operation
+/// | <kbd>Esc</kbd>         | Cancel the current operation
 /// | <kbd>↑</kbd>           | Move the selection up
 /// | <kbd>↓</kbd>           | Move the selection down
 /// | <kbd>Space</kbd>       | Toggle the checkbox state for the current item
@@ -38,7 +39,13 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
 
         // Move cursor.
         Event::Key(KeyEvent {
@@ -1,7 +1,14 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::HashSet};
 
 use crate::{
-    checkbox, crossterm::event::Event, pane::Pane, snapshot::Snapshot, switch::ActiveKeySwitcher,
+    checkbox,
+    crossterm::event::Event,
+    grapheme::StyledGraphemes,
+    pane::{Pane, Priority},
+    preset,
+    snapshot::Snapshot,
+    switch::ActiveKeySwitcher,
+    terminal::Click,
     text, PaneFactory, PromptSignal,
 };
 
@@ -18,6 +25,8 @@ pub struct Renderer {
     pub title_snapshot: Snapshot<text::State>,
     /// A snapshot of the checkbox's renderer state.
     pub checkbox_snapshot: Snapshot<checkbox::State>,
+    /// Why the last line was rejected in the line-oriented fallback, if it was.
+    pub line_error: Option<String>,
 }
 
 impl crate::Finalizer for Renderer {
@@ -33,13 +42,27 @@ impl crate::Finalizer for Renderer {
             .map(|e| e.to_string())
             .collect())
     }
+
+    fn summary(&self, result: &Self::Return) -> Option<StyledGraphemes> {
+        Some(preset::summary(
+            &self.title_snapshot.after().text,
+            result.join(", "),
+        ))
+    }
 }
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let title = self
+            .title_snapshot
+            .create_pane(width, height)
+            .with_priority(Priority::Low);
+        let height = preset::height_below(height, &[&title]);
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.checkbox_snapshot.create_pane(width, height),
+            title,
+            self.checkbox_snapshot
+                .create_pane(width, height)
+                .with_focus(),
         ]
     }
 
@@ -47,4 +70,70 @@ impl crate::Renderer for Renderer {
         let keymap = *self.keymap.borrow_mut().get();
         keymap(event, self)
     }
+
+    /// Moves the cursor to the clicked item, toggling it if the click is on its mark,
+    /// and submits on a double-click.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let Some(item) = click.item else {
+            return Ok(PromptSignal::Continue);
+        };
+        if click.count > 1 {
+            return Ok(PromptSignal::Quit);
+        }
+        let checkbox_after_mut = self.checkbox_snapshot.after_mut();
+        checkbox_after_mut.checkbox.move_to(item);
+        if checkbox_after_mut.is_on_mark(item, click.column) {
+            checkbox_after_mut.checkbox.toggle();
+        }
+        Ok(PromptSignal::Continue)
+    }
+
+    /// Lists the items with their 1-based indexes.
+    fn line_prompt(&self) -> Option<String> {
+        let mut text = String::new();
+        let title = &self.title_snapshot.after().text;
+        if !title.is_empty() {
+            text.push_str(&format!("{}\n", title));
+        }
+        let checkbox = &self.checkbox_snapshot.after().checkbox;
+        for (i, item) in checkbox.items().iter().enumerate() {
+            text.push_str(&format!("{}) {}\n", i + 1, item));
+        }
+        if let Some(error) = &self.line_error {
+            text.push_str(&format!("{}\n", error));
+        }
+        text.push_str(&self.checkbox_snapshot.after().cursor);
+        Some(text)
+    }
+
+    /// Picks the items designated by a comma-separated list of 1-based indexes or texts.
+    /// An empty line picks no items.
+    fn evaluate_line(&mut self, line: &str) -> anyhow::Result<PromptSignal> {
+        let checkbox_after_mut = &mut self.checkbox_snapshot.after_mut().checkbox;
+        let picked = match line
+            .split(',')
+            .filter(|answer| !answer.trim().is_empty())
+            .map(|answer| {
+                checkbox_after_mut
+                    .index_of(answer)
+                    .ok_or_else(|| format!("'{}' is not one of the items", answer.trim()))
+            })
+            .collect::<Result<HashSet<usize>, String>>()
+        {
+            Ok(picked) => picked,
+            Err(error) => {
+                self.line_error = Some(error);
+                return Ok(PromptSignal::Continue);
+            }
+        };
+        self.line_error = None;
+
+        for i in 0..checkbox_after_mut.items().len() {
+            if checkbox_after_mut.picked_indexes().contains(&i) != picked.contains(&i) {
+                checkbox_after_mut.move_to(i);
+                checkbox_after_mut.toggle();
+            }
+        }
+        Ok(PromptSignal::Quit)
+    }
 }
@@ -0,0 +1,315 @@
+use std::{any::Any, cell::RefCell};
+
+use crate::{
+    core::Cursor, crossterm::event::Event, grapheme::StyledGraphemes, pane::Pane,
+    switch::ActiveKeySwitcher, terminal::Click, Finalizer, Prompt, PromptSignal, Renderer,
+};
+
+pub mod keymap;
+pub mod render;
+
+/// A component that can be placed in a [`Container`].
+///
+/// It is implemented for every `Renderer` (e.g. a custom one)
+/// and for every `Prompt` (e.g. as returned by the `prompt` method of the presets),
+/// so that the components do not have to share the type of their result.
+pub trait Component {
+    /// Creates the panes of the component, as `Renderer::create_panes` does.
+    fn create_panes(&self, width: u16, height: u16) -> Vec<Pane>;
+
+    /// Evaluates an event routed to the component while it has the focus.
+    fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal>;
+
+    /// Evaluates a click on one of the panes of the component, as `Renderer::evaluate_click` does.
+    /// The pane of the click is counted among the panes of the component.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal>;
+
+    /// Checks if the component handles <kbd>Tab</kbd> itself, as `Renderer::captures_tab` does.
+    fn captures_tab(&self) -> bool;
+
+    /// Finalizes the component and produces its result.
+    fn finalize(&mut self) -> anyhow::Result<Box<dyn Any>>;
+
+    /// Summarizes a result produced by `finalize`, as `Finalizer::summary` does.
+    fn summary(&self, result: &dyn Any) -> Option<StyledGraphemes>;
+
+    /// Restores a result produced by `finalize`, as `Finalizer::restore` does.
+    fn restore(&mut self, result: Box<dyn Any>);
+}
+
+impl<R: Renderer> Component for R
+where
+    R::Return: 'static,
+{
+    fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        Renderer::create_panes(self, width, height)
+    }
+
+    fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        Renderer::evaluate(self, event)
+    }
+
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        Renderer::evaluate_click(self, click)
+    }
+
+    fn captures_tab(&self) -> bool {
+        Renderer::captures_tab(self)
+    }
+
+    fn finalize(&mut self) -> anyhow::Result<Box<dyn Any>> {
+        Ok(Box::new(Finalizer::finalize(self)?))
+    }
+
+    fn summary(&self, result: &dyn Any) -> Option<StyledGraphemes> {
+        Finalizer::summary(self, result.downcast_ref()?)
+    }
+
+    fn restore(&mut self, result: Box<dyn Any>) {
+        if let Ok(result) = result.downcast() {
+            Finalizer::restore(self, *result);
+        }
+    }
+}
+
+impl<R: Renderer> Component for Prompt<R>
+where
+    R::Return: 'static,
+{
+    fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        self.renderer.create_panes(width, height)
+    }
+
+    fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        self.renderer.evaluate(event)
+    }
+
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        self.renderer.evaluate_click(click)
+    }
+
+    fn captures_tab(&self) -> bool {
+        self.renderer.captures_tab()
+    }
+
+    fn finalize(&mut self) -> anyhow::Result<Box<dyn Any>> {
+        Ok(Box::new(self.renderer.finalize()?))
+    }
+
+    fn summary(&self, result: &dyn Any) -> Option<StyledGraphemes> {
+        self.renderer.summary(result.downcast_ref()?)
+    }
+
+    fn restore(&mut self, result: Box<dyn Any>) {
+        if let Ok(result) = result.downcast() {
+            self.renderer.restore(*result);
+        }
+    }
+}
+
+/// The results of the components of a [`Container`], in the order they were added.
+pub struct Results(pub(crate) Vec<Box<dyn Any>>);
+
+impl Results {
+    /// Returns the number of results.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if there are no results.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the result of the component at the given index,
+    /// or `None` if there is no such component or its result is not a `T`.
+    pub fn get<T: 'static>(&self, index: usize) -> Option<&T> {
+        self.0.get(index)?.downcast_ref()
+    }
+
+    /// Takes the result of the component at the given index,
+    /// after which it cannot be taken or got again.
+    pub fn take<T: 'static>(&mut self, index: usize) -> Option<T> {
+        let result = self.0.get_mut(index)?;
+        if !result.is::<T>() {
+            return None;
+        }
+        std::mem::replace(result, Box::new(()))
+            .downcast()
+            .ok()
+            .map(|t| *t)
+    }
+}
+
+/// Composes several components into a single prompt,
+/// e.g. a readline and a listbox on the same screen.
+///
+/// Events are routed to the component having the focus,
+/// which is cycled with <kbd>Tab</kbd> and <kbd>Shift + Tab</kbd>
+/// (unless the focused component uses <kbd>Tab</kbd> itself, e.g. to complete a readline).
+/// When the focused component quits (e.g. on <kbd>Enter</kbd>),
+/// the focus moves to the next one, and the container quits after the last one.
+/// With the mouse enabled, a click goes to the clicked component, which gets the focus.
+///
+/// ```no_run
+/// use promkit::preset::{container::Container, listbox::Listbox, readline::Readline};
+///
+/// fn main() -> anyhow::Result<()> {
+///     let mut p = Container::new()
+///         .component(Readline::default().title("Name").prompt()?)
+///         .component(Listbox::new(["red", "green", "blue"]).title("Color").prompt()?)
+///         .prompt()?;
+///     let mut results = p.run()?;
+///     println!("{:?}", results.take::<String>(0));
+///     println!("{:?}", results.take::<String>(1));
+///     Ok(())
+/// }
+/// ```
+pub struct Container {
+    keymap: ActiveKeySwitcher<keymap::Keymap>,
+    components: Vec<Box<dyn Component>>,
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container {
+    /// Constructs an empty `Container`.
+    pub fn new() -> Self {
+        Self {
+            keymap: ActiveKeySwitcher::new("default", self::keymap::default as keymap::Keymap),
+            components: vec![],
+        }
+    }
+
+    /// Appends a component. The first component has the focus initially.
+    pub fn component<C: Component + 'static>(mut self, component: C) -> Self {
+        self.components.push(Box::new(component));
+        self
+    }
+
+    pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
+        self.keymap = self.keymap.register(key, handler);
+        self
+    }
+
+    /// Creates a prompt showing all the components.
+    /// Returns a `Result` containing the `Prompt`,
+    /// whose result holds the results of all the components.
+    pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            components: Cursor::new(self.components, 0, true),
+            pane_counts: Default::default(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod results {
+        use super::*;
+
+        #[test]
+        fn test_get_and_take() {
+            let mut results = Results(vec![Box::new(String::from("a")), Box::new(1_usize)]);
+            assert_eq!(Some(&String::from("a")), results.get::<String>(0));
+            assert_eq!(None, results.get::<String>(1));
+            assert_eq!(None, results.take::<String>(1));
+            assert_eq!(Some(1), results.take::<usize>(1));
+            assert_eq!(None, results.take::<usize>(1));
+            assert_eq!(None, results.get::<usize>(2));
+        }
+    }
+
+    mod run {
+        use super::*;
+
+        use crate::{
+            crossterm::event::{KeyCode, KeyModifiers},
+            preset::{listbox::Listbox, readline::Readline},
+            suggest::Suggest,
+            testing::{self, key, key_with, type_text},
+        };
+
+        #[test]
+        fn test_focus_and_results() {
+            let mut prompt = Container::new()
+                .component(Readline::default().prompt().unwrap())
+                .component(Listbox::new(["a", "b", "c"]).prompt().unwrap())
+                .prompt()
+                .unwrap();
+
+            let mut events = type_text("ab");
+            events.extend([
+                key(KeyCode::Tab),
+                key(KeyCode::Down),
+                key_with(KeyCode::BackTab, KeyModifiers::SHIFT),
+                key(KeyCode::Char('c')),
+                key(KeyCode::Enter),
+                key(KeyCode::Down),
+                key(KeyCode::Enter),
+            ]);
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 10)).unwrap();
+
+            let mut results = transcript.result.unwrap().unwrap();
+            assert_eq!(2, results.len());
+            assert_eq!(Some(String::from("abc")), results.take::<String>(0));
+            assert_eq!(Some(String::from("c")), results.take::<String>(1));
+        }
+
+        #[test]
+        fn test_click() {
+            let mut prompt = Container::new()
+                .component(Readline::default().prompt().unwrap())
+                .component(Listbox::new(["a", "b", "c"]).prompt().unwrap())
+                .prompt()
+                .unwrap();
+
+            let mut events = type_text("ab");
+            // A double-click on `b` moves the focus to the listbox and submits it.
+            events.extend([testing::click(2, 2), testing::click(2, 2)]);
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 10)).unwrap();
+
+            let mut results = transcript.result.unwrap().unwrap();
+            assert_eq!(Some(String::from("ab")), results.take::<String>(0));
+            assert_eq!(Some(String::from("b")), results.take::<String>(1));
+        }
+
+        #[test]
+        fn test_tab_completion() {
+            let mut prompt = Container::new()
+                .component(
+                    Readline::default()
+                        .enable_suggest(Suggest::from_iter(["apple"]))
+                        .prompt()
+                        .unwrap(),
+                )
+                .component(Listbox::new(["a", "b"]).prompt().unwrap())
+                .prompt()
+                .unwrap();
+
+            let mut events = type_text("ap");
+            // Tab completes the input, and Enter closes the suggestions.
+            events.extend([key(KeyCode::Tab), key(KeyCode::Tab), key(KeyCode::Enter)]);
+            // Nothing is left to complete, so Tab moves the focus.
+            events.extend([
+                key(KeyCode::Tab),
+                key(KeyCode::Down),
+                key_with(KeyCode::BackTab, KeyModifiers::SHIFT),
+                key(KeyCode::Enter),
+                key(KeyCode::Enter),
+            ]);
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 10)).unwrap();
+
+            let mut results = transcript.result.unwrap().unwrap();
+            assert_eq!(Some(String::from("apple")), results.take::<String>(0));
+            assert_eq!(Some(String::from("b")), results.take::<String>(1));
+        }
+    }
+}
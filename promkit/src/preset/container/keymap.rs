@@ -0,0 +1,42 @@
+use crate::{
+    crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers},
+    preset, PromptSignal, Renderer,
+};
+
+pub type Keymap = fn(
+    event: &Event,
+    renderer: &mut preset::container::render::Renderer,
+) -> anyhow::Result<PromptSignal>;
+
+/// Default key bindings for the container.
+///
+/// | Key                    | Action
+/// | :--------------------- | :-------------------------------------------
+/// | <kbd>Tab</kbd>         | Move the focus to the next component
+/// | <kbd>Shift + Tab</kbd> | Move the focus to the previous component
+/// | Others                 | Handled by the key bindings of the focused component
+///
+/// <kbd>Tab</kbd> is passed to the focused component instead
+/// while it captures it, e.g. while a readline shows its suggestions.
+pub fn default(
+    event: &Event,
+    renderer: &mut preset::container::render::Renderer,
+) -> anyhow::Result<PromptSignal> {
+    match event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Tab,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if !renderer.captures_tab() => renderer.focus_next(),
+        Event::Key(KeyEvent {
+            code: KeyCode::BackTab,
+            modifiers: KeyModifiers::SHIFT,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => renderer.focus_previous(),
+
+        _ => return renderer.evaluate_focused(event),
+    }
+    Ok(PromptSignal::Continue)
+}
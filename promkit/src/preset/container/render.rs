@@ -0,0 +1,126 @@
+use std::cell::RefCell;
+
+use crate::{
+    core::Cursor, crossterm::event::Event, pane::Pane, switch::ActiveKeySwitcher, terminal::Click,
+    PromptSignal,
+};
+
+use super::{keymap, Component, Results};
+
+/// Renders the components of a container, routing the events to the focused one.
+pub struct Renderer {
+    /// A mutable reference to a key switcher that manages active key mappings.
+    pub keymap: RefCell<ActiveKeySwitcher<keymap::Keymap>>,
+    /// Cursor pointing to the component having the focus.
+    pub components: Cursor<Vec<Box<dyn Component>>>,
+    /// The number of panes that each component created at the last draw,
+    /// to find the component that a click is on.
+    pub pane_counts: RefCell<Vec<usize>>,
+}
+
+impl crate::Finalizer for Renderer {
+    type Return = Results;
+
+    fn finalize(&mut self) -> anyhow::Result<Self::Return> {
+        Ok(Results(
+            self.components
+                .contents_mut()
+                .iter_mut()
+                .map(|component| component.finalize())
+                .collect::<anyhow::Result<_>>()?,
+        ))
+    }
+}
+
+impl Renderer {
+    /// Moves the focus to the next component, cycling to the first one.
+    pub fn focus_next(&mut self) {
+        self.components.forward();
+    }
+
+    /// Moves the focus to the previous component, cycling to the last one.
+    pub fn focus_previous(&mut self) {
+        self.components.backward();
+    }
+
+    /// Routes the event to the focused component.
+    ///
+    /// When the component quits, the focus moves to the next one,
+    /// or the container quits if it is the last.
+    pub fn evaluate_focused(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        self.with_focused(|component| component.evaluate(event))
+    }
+
+    /// Lets the focused component evaluate an input in the same way as `evaluate_focused`.
+    fn with_focused<F>(&mut self, evaluate: F) -> anyhow::Result<PromptSignal>
+    where
+        F: FnOnce(&mut dyn Component) -> anyhow::Result<PromptSignal>,
+    {
+        let position = self.components.position();
+        let Some(component) = self.components.contents_mut().get_mut(position) else {
+            return Ok(PromptSignal::Quit);
+        };
+        if evaluate(component.as_mut())? == PromptSignal::Continue {
+            return Ok(PromptSignal::Continue);
+        }
+        if position + 1 < self.components.contents().len() {
+            self.focus_next();
+            Ok(PromptSignal::Continue)
+        } else {
+            Ok(PromptSignal::Quit)
+        }
+    }
+}
+
+impl crate::Renderer for Renderer {
+    fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let position = self.components.position();
+        let mut pane_counts = self.pane_counts.borrow_mut();
+        pane_counts.clear();
+        self.components
+            .contents()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, component)| {
+                let panes = component.create_panes(width, height);
+                pane_counts.push(panes.len());
+                if i == position {
+                    panes.into_iter().map(|pane| pane.with_focus()).collect()
+                } else {
+                    panes
+                }
+            })
+            .collect()
+    }
+
+    fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        let keymap = *self.keymap.borrow_mut().get();
+        keymap(event, self)
+    }
+
+    /// Routes the click to the component whose panes were clicked,
+    /// moving the focus to it first if necessary.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let mut pane = click.pane;
+        let mut component = None;
+        for (i, count) in self.pane_counts.borrow().iter().enumerate() {
+            if pane < *count {
+                component = Some(i);
+                break;
+            }
+            pane -= count;
+        }
+        let Some(component) = component else {
+            return Ok(PromptSignal::Continue);
+        };
+        self.components.move_to(component);
+        self.with_focused(|component| component.evaluate_click(&Click { pane, ..*click }))
+    }
+
+    fn captures_tab(&self) -> bool {
+        self.components
+            .contents()
+            .get(self.components.position())
+            .is_some_and(|component| component.captures_tab())
+    }
+}
@@ -62,6 +62,6 @@ impl Form {
             overwrite_styles: self.overwrite_styles,
         };
         renderer.overwrite_styles();
-        Ok(Prompt { renderer })
+        Ok(Prompt::new(renderer))
     }
 }
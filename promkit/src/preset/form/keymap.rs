@@ -1,6 +1,6 @@
 use crate::{
     crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers},
-    preset, text_editor, PromptSignal,
+    preset, text_editor, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -26,7 +26,25 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('d'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if renderer.text_editor_states.contents()[current_position]
+            .texteditor
+            .text_without_cursor()
+            .is_empty() =>
+        {
+            return Err(PromptError::Eof.into())
+        }
 
         // Move cursor.
         Event::Key(KeyEvent {
@@ -182,6 +200,11 @@ pub fn default(
                 .overwrite(*ch),
         },
 
+        // Input pasted text.
+        Event::Paste(text) => {
+            renderer.text_editor_states.contents_mut()[current_position].paste(text)
+        }
+
         _ => (),
     }
     Ok(PromptSignal::Continue)
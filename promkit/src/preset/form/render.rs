@@ -69,10 +69,19 @@ impl Renderer {
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let current_position = self.text_editor_states.position();
         self.text_editor_states
             .contents()
             .iter()
-            .map(|state| state.create_pane(width, height))
+            .enumerate()
+            .map(|(i, state)| {
+                let pane = state.create_pane(width, height);
+                if i == current_position {
+                    pane.with_focus()
+                } else {
+                    pane
+                }
+            })
             .collect()
     }
 
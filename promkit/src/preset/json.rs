@@ -2,6 +2,7 @@ use std::cell::RefCell;
 
 use crate::{
     crossterm::style::{Attribute, Attributes, Color, ContentStyle},
+    indicator::Indicator,
     json::{self, JsonStream},
     snapshot::Snapshot,
     style::StyleBuilder,
@@ -44,7 +45,10 @@ impl Json {
                 active_item_attribute: Attribute::Undercurled,
                 inactive_item_attribute: Attribute::Dim,
                 lines: Default::default(),
+                indicator: Default::default(),
                 indent: 2,
+                wrap: true,
+                horizontal_offset: 0,
             },
             keymap: ActiveKeySwitcher::new("default", self::keymap::default),
         }
@@ -68,6 +72,12 @@ impl Json {
         self
     }
 
+    /// Sets the scrollbar and position footer shown with the JSON data.
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.json_state.indicator = indicator;
+        self
+    }
+
     /// Sets the indentation level for rendering the JSON data.
     pub fn indent(mut self, indent: usize) -> Self {
         self.json_state.indent = indent;
@@ -86,6 +96,13 @@ impl Json {
         self
     }
 
+    /// Truncates long lines at the pane width instead of wrapping them,
+    /// allowing to scroll horizontally with the left and right keys.
+    pub fn no_wrap(mut self) -> Self {
+        self.json_state.wrap = false;
+        self
+    }
+
     pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
         self.keymap = self.keymap.register(key, handler);
         self
@@ -93,12 +110,10 @@ impl Json {
 
     /// Creates a prompt based on the current configuration of the `Json` instance.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                json_snapshot: Snapshot::<json::State>::new(self.json_state),
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            json_snapshot: Snapshot::<json::State>::new(self.json_state),
+        }))
     }
 }
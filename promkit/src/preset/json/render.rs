@@ -4,9 +4,11 @@ use crate::{
     crossterm::event::Event,
     json,
     json::{JsonNode, JsonPath},
-    pane::Pane,
+    pane::{Pane, Priority},
+    preset,
     snapshot::Snapshot,
     switch::ActiveKeySwitcher,
+    terminal::Click,
     text, PaneFactory, PromptSignal,
 };
 
@@ -37,9 +39,14 @@ impl crate::Finalizer for Renderer {
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let title = self
+            .title_snapshot
+            .create_pane(width, height)
+            .with_priority(Priority::Low);
+        let height = preset::height_below(height, &[&title]);
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.json_snapshot.create_pane(width, height),
+            title,
+            self.json_snapshot.create_pane(width, height).with_focus(),
         ]
     }
 
@@ -47,4 +54,16 @@ impl crate::Renderer for Renderer {
         let keymap = *self.keymap.borrow_mut().get();
         keymap(event, self)
     }
+
+    /// Moves the cursor to the clicked row, and submits on a double-click.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let Some(item) = click.item else {
+            return Ok(PromptSignal::Continue);
+        };
+        self.json_snapshot.after_mut().stream.move_to(item);
+        if click.count > 1 {
+            return Ok(PromptSignal::Quit);
+        }
+        Ok(PromptSignal::Continue)
+    }
 }
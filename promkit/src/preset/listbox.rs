@@ -2,6 +2,7 @@ use std::{cell::RefCell, fmt::Display};
 
 use crate::{
     crossterm::style::{Attribute, Attributes, Color, ContentStyle},
+    indicator::Indicator,
     listbox,
     snapshot::Snapshot,
     style::StyleBuilder,
@@ -43,6 +44,9 @@ impl Listbox {
                 active_item_style: Some(StyleBuilder::new().fgc(Color::DarkCyan).build()),
                 inactive_item_style: Some(StyleBuilder::new().build()),
                 lines: Default::default(),
+                indicator: Default::default(),
+                wrap: true,
+                horizontal_offset: 0,
             },
             keymap: ActiveKeySwitcher::new("default", self::keymap::default),
         }
@@ -84,6 +88,19 @@ impl Listbox {
         self
     }
 
+    /// Sets the scrollbar and position footer shown with the items.
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.listbox_state.indicator = indicator;
+        self
+    }
+
+    /// Truncates long items at the pane width instead of wrapping them,
+    /// allowing to scroll horizontally with the left and right keys.
+    pub fn no_wrap(mut self) -> Self {
+        self.listbox_state.wrap = false;
+        self
+    }
+
     pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
         self.keymap = self.keymap.register(key, handler);
         self
@@ -93,12 +110,52 @@ impl Listbox {
     /// Returns a `Result` containing the `Prompt` result,
     /// which is the selected option.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                listbox_snapshot: Snapshot::<listbox::State>::new(self.listbox_state),
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            listbox_snapshot: Snapshot::<listbox::State>::new(self.listbox_state),
+            line_error: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod run {
+        use super::*;
+
+        use crate::testing::{self, click};
+
+        #[test]
+        fn test_double_click() {
+            let mut prompt = Listbox::new(["a", "b", "c"]).prompt().unwrap();
+            // The title is empty, so the items start from the top.
+            let events = [click(3, 1), click(3, 1)];
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+            // The list is scrolled to `b`, and the second click still counts on it.
+            assert_eq!("❯ b", transcript.frames[1].rows()[0]);
+            assert_eq!(String::from("b"), transcript.result.unwrap().unwrap());
+        }
+
+        #[test]
+        fn test_footer_below_title() {
+            let mut prompt = Listbox::new(["a", "b", "c", "d", "e", "f", "g", "h"])
+                .title("Pick")
+                .indicator(Indicator {
+                    scrollbar: true,
+                    footer: true,
+                    ..Default::default()
+                })
+                .prompt()
+                .unwrap();
+            let transcript = testing::run(&mut prompt.renderer, [], (6, 5)).unwrap();
+            // The items share the rows below the title with the footer.
+            assert_eq!(
+                vec!["Pick", "❯ a  ┃", "  b  ┃", "  c  │", "1/8"],
+                transcript.frames[0].rows()
+            );
+        }
     }
 }
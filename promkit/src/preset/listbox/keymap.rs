@@ -3,7 +3,7 @@ use crate::{
         Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent,
         MouseEventKind,
     },
-    preset, PromptSignal,
+    preset, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -17,8 +17,11 @@ pub type Keymap = fn(
 /// | :--------------------- | :-------------------------------------------
 /// | <kbd>Enter</kbd>       | Exit the listbox
 /// | <kbd>Ctrl + C</kbd>    | Interrupt the current operation
+/// | <kbd>Esc</kbd>         | Cancel the current operation
 /// | <kbd>↑</kbd>           | Move the selection up
 /// | <kbd>↓</kbd>           | Move the selection down
+/// | <kbd>←</kbd>           | Scroll to the left when not wrapping
+/// | <kbd>→</kbd>           | Scroll to the right when not wrapping
 pub fn default(
     event: &Event,
     renderer: &mut preset::listbox::render::Renderer,
@@ -37,7 +40,13 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
 
         // Move cursor.
         Event::Key(KeyEvent {
@@ -70,6 +79,24 @@ pub fn default(
             listbox_after_mut.listbox.forward();
         }
 
+        // Scroll horizontally.
+        Event::Key(KeyEvent {
+            code: KeyCode::Left,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if !listbox_after_mut.wrap => {
+            listbox_after_mut.scroll_left();
+        }
+        Event::Key(KeyEvent {
+            code: KeyCode::Right,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if !listbox_after_mut.wrap => {
+            listbox_after_mut.scroll_right();
+        }
+
         _ => (),
     }
     Ok(PromptSignal::Continue)
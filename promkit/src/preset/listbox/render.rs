@@ -1,7 +1,14 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, fmt};
 
 use crate::{
-    crossterm::event::Event, listbox, pane::Pane, snapshot::Snapshot, switch::ActiveKeySwitcher,
+    crossterm::event::Event,
+    grapheme::StyledGraphemes,
+    listbox,
+    pane::{Pane, Priority},
+    preset,
+    snapshot::Snapshot,
+    switch::ActiveKeySwitcher,
+    terminal::Click,
     text, PaneFactory, PromptSignal,
 };
 
@@ -11,6 +18,18 @@ pub struct Renderer {
     pub keymap: RefCell<ActiveKeySwitcher<keymap::Keymap>>,
     pub title_snapshot: Snapshot<text::State>,
     pub listbox_snapshot: Snapshot<listbox::State>,
+    /// Why the last line was rejected in the line-oriented fallback, if it was.
+    pub line_error: Option<String>,
+}
+
+impl Renderer {
+    /// Appends the given items to the list, keeping the cursor at the current item.
+    ///
+    /// This is intended to be sent through an [`Updater`](crate::update::Updater)
+    /// to fill the list while the prompt is running.
+    pub fn push_items<E: fmt::Display, I: IntoIterator<Item = E>>(&mut self, items: I) {
+        self.listbox_snapshot.after_mut().listbox.extend(items);
+    }
 }
 
 impl crate::Finalizer for Renderer {
@@ -19,13 +38,24 @@ impl crate::Finalizer for Renderer {
     fn finalize(&mut self) -> anyhow::Result<Self::Return> {
         Ok(self.listbox_snapshot.after().listbox.get().to_string())
     }
+
+    fn summary(&self, result: &Self::Return) -> Option<StyledGraphemes> {
+        Some(preset::summary(&self.title_snapshot.after().text, result))
+    }
 }
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let title = self
+            .title_snapshot
+            .create_pane(width, height)
+            .with_priority(Priority::Low);
+        let height = preset::height_below(height, &[&title]);
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.listbox_snapshot.create_pane(width, height),
+            title,
+            self.listbox_snapshot
+                .create_pane(width, height)
+                .with_focus(),
         ]
     }
 
@@ -33,4 +63,50 @@ impl crate::Renderer for Renderer {
         let keymap = *self.keymap.borrow_mut().get();
         keymap(event, self)
     }
+
+    /// Moves the cursor to the clicked item, and submits it on a double-click.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let Some(item) = click.item else {
+            return Ok(PromptSignal::Continue);
+        };
+        self.listbox_snapshot.after_mut().listbox.move_to(item);
+        if click.count > 1 {
+            return Ok(PromptSignal::Quit);
+        }
+        Ok(PromptSignal::Continue)
+    }
+
+    /// Lists the items with their 1-based indexes.
+    fn line_prompt(&self) -> Option<String> {
+        let mut text = String::new();
+        let title = &self.title_snapshot.after().text;
+        if !title.is_empty() {
+            text.push_str(&format!("{}\n", title));
+        }
+        let listbox = &self.listbox_snapshot.after().listbox;
+        for (i, item) in listbox.items().iter().enumerate() {
+            text.push_str(&format!("{}) {}\n", i + 1, item));
+        }
+        if let Some(error) = &self.line_error {
+            text.push_str(&format!("{}\n", error));
+        }
+        text.push_str(&self.listbox_snapshot.after().cursor);
+        Some(text)
+    }
+
+    /// Selects the item designated by its 1-based index or its text.
+    fn evaluate_line(&mut self, line: &str) -> anyhow::Result<PromptSignal> {
+        let listbox_after_mut = &mut self.listbox_snapshot.after_mut().listbox;
+        match listbox_after_mut.index_of(line) {
+            Some(index) => {
+                listbox_after_mut.move_to(index);
+                self.line_error = None;
+                Ok(PromptSignal::Quit)
+            }
+            None => {
+                self.line_error = Some(format!("'{}' is not one of the items", line.trim()));
+                Ok(PromptSignal::Continue)
+            }
+        }
+    }
 }
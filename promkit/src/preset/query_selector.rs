@@ -7,7 +7,7 @@ use crate::{
     style::StyleBuilder,
     switch::ActiveKeySwitcher,
     text,
-    text_editor::{self, Mode},
+    text_editor::{self, Mode, NewlinePolicy},
     Prompt,
 };
 
@@ -63,6 +63,7 @@ impl QuerySelector {
                 inactive_char_style: StyleBuilder::new().build(),
                 edit_mode: Default::default(),
                 word_break_chars: Default::default(),
+                paste_newlines: Default::default(),
                 lines: Default::default(),
             },
             listbox_state: listbox::State {
@@ -71,6 +72,9 @@ impl QuerySelector {
                 active_item_style: Some(StyleBuilder::new().fgc(Color::DarkCyan).build()),
                 inactive_item_style: Some(StyleBuilder::new().build()),
                 lines: Default::default(),
+                indicator: Default::default(),
+                wrap: true,
+                horizontal_offset: 0,
             },
             keymap: ActiveKeySwitcher::new("default", self::keymap::default),
             filter,
@@ -119,6 +123,12 @@ impl QuerySelector {
         self
     }
 
+    /// Sets how newlines in pasted text are handled.
+    pub fn paste_newlines(mut self, policy: NewlinePolicy) -> Self {
+        self.text_editor_state.paste_newlines = policy;
+        self
+    }
+
     /// Sets the number of lines available for the text editor component.
     pub fn text_editor_lines(mut self, lines: usize) -> Self {
         self.text_editor_state.lines = Some(lines);
@@ -158,14 +168,12 @@ impl QuerySelector {
     /// Returns a `Result` containing the `Prompt` result,
     /// which is the selected option.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                text_editor_snapshot: Snapshot::<text_editor::State>::new(self.text_editor_state),
-                listbox_snapshot: Snapshot::<listbox::State>::new(self.listbox_state),
-                filter: self.filter,
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            text_editor_snapshot: Snapshot::<text_editor::State>::new(self.text_editor_state),
+            listbox_snapshot: Snapshot::<listbox::State>::new(self.listbox_state),
+            filter: self.filter,
+        }))
     }
 }
@@ -1,6 +1,9 @@
 use crate::{
-    crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers},
-    preset, text_editor, PromptSignal,
+    crossterm::event::{
+        Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent,
+        MouseEventKind,
+    },
+    preset, text_editor, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -27,7 +30,13 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
 
         // Move cursor.
         Event::Key(KeyEvent {
@@ -79,6 +88,12 @@ pub fn default(
             modifiers: KeyModifiers::NONE,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
+        })
+        | Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            column: _,
+            row: _,
+            modifiers: KeyModifiers::NONE,
         }) => {
             listbox_after_mut.listbox.backward();
         }
@@ -88,6 +103,12 @@ pub fn default(
             modifiers: KeyModifiers::NONE,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
+        })
+        | Event::Mouse(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: _,
+            row: _,
+            modifiers: KeyModifiers::NONE,
         }) => {
             listbox_after_mut.listbox.forward();
         }
@@ -109,6 +130,9 @@ pub fn default(
             text_editor::Mode::Overwrite => text_editor_after_mut.texteditor.overwrite(*ch),
         },
 
+        // Input pasted text.
+        Event::Paste(text) => text_editor_after_mut.paste(text),
+
         _ => (),
     }
     Ok(PromptSignal::Continue)
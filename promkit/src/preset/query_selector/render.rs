@@ -1,11 +1,14 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, fmt};
 
 use crate::{
     crossterm::event::Event,
+    grapheme::StyledGraphemes,
     listbox::{self, Listbox},
-    pane::Pane,
+    pane::{Pane, Priority},
+    preset,
     snapshot::Snapshot,
     switch::ActiveKeySwitcher,
+    terminal::Click,
     text, text_editor, PaneFactory, PromptSignal,
 };
 
@@ -30,19 +33,56 @@ pub struct Renderer {
     pub filter: Filter,
 }
 
+impl Renderer {
+    /// Appends the given items to the candidates, keeping the cursor at the current item.
+    ///
+    /// The items that pass the filter with the current query are shown immediately.
+    /// This is intended to be sent through an [`Updater`](crate::update::Updater)
+    /// to stream candidates while the prompt is running.
+    pub fn push_items<E: fmt::Display, I: IntoIterator<Item = E>>(&mut self, items: I) {
+        let items: Vec<String> = items.into_iter().map(|e| e.to_string()).collect();
+        self.listbox_snapshot
+            .init_mut()
+            .listbox
+            .extend(items.iter());
+
+        let query = self
+            .text_editor_snapshot
+            .after()
+            .texteditor
+            .text_without_cursor()
+            .to_string();
+        let list = (self.filter)(&query, &items);
+        self.listbox_snapshot.after_mut().listbox.extend(list);
+    }
+}
+
 impl crate::Finalizer for Renderer {
     type Return = String;
 
     fn finalize(&mut self) -> anyhow::Result<Self::Return> {
         Ok(self.listbox_snapshot.after().listbox.get().to_string())
     }
+
+    fn summary(&self, result: &Self::Return) -> Option<StyledGraphemes> {
+        Some(preset::summary(&self.title_snapshot.after().text, result))
+    }
 }
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let title = self
+            .title_snapshot
+            .create_pane(width, height)
+            .with_priority(Priority::Low);
+        let text_editor = self
+            .text_editor_snapshot
+            .create_pane(width, height)
+            .with_focus();
+        let height = preset::height_below(height, &[&title, &text_editor]);
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.text_editor_snapshot.create_pane(width, height),
+            title,
+            text_editor,
             self.listbox_snapshot.create_pane(width, height),
         ]
     }
@@ -75,4 +115,16 @@ impl crate::Renderer for Renderer {
         }
         signal
     }
+
+    /// Moves the cursor to the clicked item, and submits it on a double-click.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let Some(item) = click.item else {
+            return Ok(PromptSignal::Continue);
+        };
+        self.listbox_snapshot.after_mut().listbox.move_to(item);
+        if click.count > 1 {
+            return Ok(PromptSignal::Quit);
+        }
+        Ok(PromptSignal::Continue)
+    }
 }
@@ -57,6 +57,7 @@ impl Default for Readline {
                 inactive_char_style: StyleBuilder::new().build(),
                 edit_mode: Default::default(),
                 word_break_chars: HashSet::from([' ']),
+                paste_newlines: Default::default(),
                 lines: Default::default(),
             },
             suggest: Default::default(),
@@ -71,6 +72,9 @@ impl Default for Readline {
                 ),
                 inactive_item_style: Some(StyleBuilder::new().fgc(Color::DarkGrey).build()),
                 lines: Some(3),
+                indicator: Default::default(),
+                wrap: true,
+                horizontal_offset: 0,
             },
             validator: Default::default(),
             error_message_state: text::State {
@@ -145,6 +149,12 @@ impl Readline {
         self
     }
 
+    /// Sets how newlines in pasted text are handled.
+    pub fn paste_newlines(mut self, policy: text_editor::NewlinePolicy) -> Self {
+        self.text_editor_state.paste_newlines = policy;
+        self
+    }
+
     /// Sets the characters to be for word break.
     pub fn word_break_chars(mut self, characters: HashSet<char>) -> Self {
         self.text_editor_state.word_break_chars = characters;
@@ -175,16 +185,91 @@ impl Readline {
     /// Initiates the prompt process,
     /// displaying the configured UI elements and handling user input.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                text_editor_snapshot: Snapshot::<text_editor::State>::new(self.text_editor_state),
-                suggest: self.suggest,
-                suggest_snapshot: Snapshot::<listbox::State>::new(self.suggest_state),
-                validator: self.validator,
-                error_message_snapshot: Snapshot::<text::State>::new(self.error_message_state),
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            text_editor_snapshot: Snapshot::<text_editor::State>::new(self.text_editor_state),
+            suggest: self.suggest,
+            suggest_snapshot: Snapshot::<listbox::State>::new(self.suggest_state),
+            validator: self.validator,
+            error_message_snapshot: Snapshot::<text::State>::new(self.error_message_state),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod run {
+        use super::*;
+
+        use crate::{
+            crossterm::event::{Event, KeyCode},
+            testing::{self, key},
+        };
+
+        #[test]
+        fn test_paste() {
+            let mut prompt = Readline::default().prompt().unwrap();
+            let events = [
+                Event::Paste(String::from("foo\nbar")),
+                key(KeyCode::Char('!')),
+                key(KeyCode::Enter),
+            ];
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+
+            // The newline neither submits the input nor is inserted.
+            assert_eq!(
+                Some("❯❯ foobar!"),
+                transcript.frames[2].rows().first().map(String::as_str)
+            );
+            assert_eq!(String::from("foobar!"), transcript.result.unwrap().unwrap());
+        }
+
+        #[test]
+        fn test_esc_on_suggest() {
+            let mut prompt = Readline::default()
+                .enable_suggest(Suggest::from_iter(["apple", "apricot"]))
+                .prompt()
+                .unwrap();
+            let mut events = testing::type_text("ap");
+            events.extend([
+                key(KeyCode::Tab),
+                key(KeyCode::Esc),
+                key(KeyCode::Char('!')),
+                key(KeyCode::Enter),
+            ]);
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+
+            // Esc only dismisses the suggestions, and the input goes on.
+            assert_eq!(String::from("apple!"), transcript.result.unwrap().unwrap());
+        }
+
+        #[test]
+        fn test_paste_rejected() {
+            let mut prompt = Readline::default()
+                .paste_newlines(text_editor::NewlinePolicy::Reject)
+                .prompt()
+                .unwrap();
+            let events = [
+                Event::Paste(String::from("foo\nbar")),
+                Event::Paste(String::from("baz")),
+                key(KeyCode::Enter),
+            ];
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+            assert_eq!(String::from("baz"), transcript.result.unwrap().unwrap());
+        }
+
+        #[test]
+        fn test_paste_control_characters() {
+            let mut prompt = Readline::default().prompt().unwrap();
+            let events = [
+                Event::Paste(String::from("a\x1b[31mb\x07c")),
+                key(KeyCode::Enter),
+            ];
+            let transcript = testing::run(&mut prompt.renderer, events, (20, 5)).unwrap();
+            assert_eq!(String::from("a[31mbc"), transcript.result.unwrap().unwrap());
+        }
     }
 }
@@ -3,7 +3,7 @@ use crossterm::style::ContentStyle;
 use crate::{
     crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers},
     listbox::Listbox,
-    preset, text_editor, PromptSignal,
+    preset, text_editor, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -17,6 +17,8 @@ pub type Keymap = fn(
 /// | :--------------------- | :-------------------------------------------
 /// | <kbd>Enter</kbd>       | Exit the editor if input is valid, otherwise show error message
 /// | <kbd>Ctrl + C</kbd>    | Interrupt the current operation
+/// | <kbd>Esc</kbd>         | Cancel the current operation
+/// | <kbd>Ctrl + D</kbd>    | Signal the end of input if the input is empty
 /// | <kbd>←</kbd>           | Move the cursor one character to the left
 /// | <kbd>→</kbd>           | Move the cursor one character to the right
 /// | <kbd>Ctrl + A</kbd>    | Move the cursor to the start of the line
@@ -30,6 +32,7 @@ pub type Keymap = fn(
 /// | <kbd>Alt + F</kbd>     | Move the cursor to the next nearest character within set (default: whitespace)
 /// | <kbd>Ctrl + W</kbd>    | Erase to the previous nearest character within set (default: whitespace)
 /// | <kbd>Alt + D</kbd>     | Erase to the next nearest character within set (default: whitespace)
+/// | Paste                  | Insert the pasted text, handling newlines as configured
 pub fn default(
     event: &Event,
     renderer: &mut preset::readline::render::Renderer,
@@ -79,7 +82,25 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('d'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if text_editor_after_mut
+            .texteditor
+            .text_without_cursor()
+            .is_empty() =>
+        {
+            return Err(PromptError::Eof.into())
+        }
 
         Event::Key(KeyEvent {
             code: KeyCode::Tab,
@@ -228,6 +249,9 @@ pub fn default(
             text_editor::Mode::Overwrite => text_editor_after_mut.texteditor.overwrite(*ch),
         },
 
+        // Input pasted text.
+        Event::Paste(text) => text_editor_after_mut.paste(text),
+
         _ => (),
     }
     Ok(PromptSignal::Continue)
@@ -246,7 +270,7 @@ pub fn on_suggest(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
 
         Event::Key(KeyEvent {
             code: KeyCode::Tab,
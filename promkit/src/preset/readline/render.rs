@@ -1,9 +1,17 @@
 use std::cell::RefCell;
 
 use crate::{
-    crossterm::event::Event, listbox, pane::Pane, snapshot::Snapshot, suggest::Suggest,
-    switch::ActiveKeySwitcher, text, text_editor, validate::ValidatorManager, PaneFactory,
-    PromptSignal,
+    crossterm::event::Event,
+    grapheme::StyledGraphemes,
+    listbox,
+    pane::{Pane, Priority},
+    preset,
+    snapshot::Snapshot,
+    suggest::Suggest,
+    switch::ActiveKeySwitcher,
+    text, text_editor,
+    validate::ValidatorManager,
+    PaneFactory, PromptSignal,
 };
 
 use super::keymap;
@@ -40,15 +48,46 @@ impl crate::Finalizer for Renderer {
         self.text_editor_snapshot.reset_after_to_init();
         Ok(ret)
     }
+
+    fn summary(&self, result: &Self::Return) -> Option<StyledGraphemes> {
+        let title = &self.title_snapshot.after().text;
+        let text_editor = self.text_editor_snapshot.after();
+        let question = if title.is_empty() {
+            &text_editor.prefix
+        } else {
+            title
+        };
+        let answer = match text_editor.mask {
+            Some(mask) => mask.to_string().repeat(StyledGraphemes::from(result).len()),
+            None => result.clone(),
+        };
+        Some(preset::summary(question, answer))
+    }
+
+    fn restore(&mut self, result: Self::Return) {
+        // `finalize` resets the text, so put the answer back.
+        self.text_editor_snapshot
+            .after_mut()
+            .texteditor
+            .replace(&result);
+    }
 }
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.error_message_snapshot.create_pane(width, height),
-            self.text_editor_snapshot.create_pane(width, height),
-            self.suggest_snapshot.create_pane(width, height),
+            self.title_snapshot
+                .create_pane(width, height)
+                .with_priority(Priority::Low),
+            self.error_message_snapshot
+                .create_pane(width, height)
+                .with_priority(Priority::Low),
+            self.text_editor_snapshot
+                .create_pane(width, height)
+                .with_focus(),
+            self.suggest_snapshot
+                .create_pane(width, height)
+                .with_priority(Priority::Low),
         ]
     }
 
@@ -56,4 +95,63 @@ impl crate::Renderer for Renderer {
         let keymap = *self.keymap.borrow_mut().get();
         keymap(event, self)
     }
+
+    fn captures_tab(&self) -> bool {
+        // Tab cycles the suggestions while they are shown,
+        // and shows them if there is something left to complete.
+        if !self.suggest_snapshot.after().listbox.items().is_empty() {
+            return true;
+        }
+        let text = self
+            .text_editor_snapshot
+            .after()
+            .texteditor
+            .text_without_cursor()
+            .to_string();
+        self.suggest
+            .as_ref()
+            .and_then(|suggest| suggest.prefix_search(&text))
+            .is_some_and(|candidates| candidates.iter().any(|candidate| *candidate != text))
+    }
+
+    fn line_prompt(&self) -> Option<String> {
+        let mut text = String::new();
+        for line in [
+            &self.title_snapshot.after().text,
+            &self.error_message_snapshot.after().text,
+        ] {
+            if !line.is_empty() {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        text.push_str(&self.text_editor_snapshot.after().prefix);
+        Some(text)
+    }
+
+    fn evaluate_line(&mut self, line: &str) -> anyhow::Result<PromptSignal> {
+        let valid = self
+            .validator
+            .as_ref()
+            .map(|validator| {
+                let valid = validator.validate(line);
+                self.error_message_snapshot.after_mut().text = if valid {
+                    String::new()
+                } else {
+                    validator.generate_error_message(line)
+                };
+                valid
+            })
+            .unwrap_or(true);
+        if !valid {
+            return Ok(PromptSignal::Continue);
+        }
+
+        let text_editor_after_mut = self.text_editor_snapshot.after_mut();
+        text_editor_after_mut.texteditor.replace(line);
+        if let Some(ref mut history) = &mut text_editor_after_mut.history {
+            history.insert(line);
+        }
+        Ok(PromptSignal::Quit)
+    }
 }
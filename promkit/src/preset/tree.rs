@@ -2,6 +2,7 @@ use std::cell::RefCell;
 
 use crate::{
     crossterm::style::{Attribute, Attributes, Color, ContentStyle},
+    indicator::Indicator,
     snapshot::Snapshot,
     style::StyleBuilder,
     switch::ActiveKeySwitcher,
@@ -45,7 +46,10 @@ impl Tree {
                 active_item_style: StyleBuilder::new().fgc(Color::DarkCyan).build(),
                 inactive_item_style: StyleBuilder::new().build(),
                 lines: Default::default(),
+                indicator: Default::default(),
                 indent: 2,
+                wrap: true,
+                horizontal_offset: 0,
             },
         }
     }
@@ -92,12 +96,25 @@ impl Tree {
         self
     }
 
+    /// Sets the scrollbar and position footer shown with the tree.
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.tree_state.indicator = indicator;
+        self
+    }
+
     /// Sets the indentation level for rendering the tree data.
     pub fn indent(mut self, indent: usize) -> Self {
         self.tree_state.indent = indent;
         self
     }
 
+    /// Truncates long items at the pane width instead of wrapping them,
+    /// allowing to scroll horizontally with the left and right keys.
+    pub fn no_wrap(mut self) -> Self {
+        self.tree_state.wrap = false;
+        self
+    }
+
     pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
         self.keymap = self.keymap.register(key, handler);
         self
@@ -107,12 +124,10 @@ impl Tree {
     /// Returns a `Result` containing the `Prompt` result,
     /// which is a list of selected options.
     pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
-        Ok(Prompt {
-            renderer: render::Renderer {
-                keymap: RefCell::new(self.keymap),
-                title_snapshot: Snapshot::<text::State>::new(self.title_state),
-                tree_snapshot: Snapshot::<tree::State>::new(self.tree_state),
-            },
-        })
+        Ok(Prompt::new(render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            title_snapshot: Snapshot::<text::State>::new(self.title_state),
+            tree_snapshot: Snapshot::<tree::State>::new(self.tree_state),
+        }))
     }
 }
@@ -3,7 +3,7 @@ use crate::{
         Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent,
         MouseEventKind,
     },
-    preset, PromptSignal,
+    preset, PromptError, PromptSignal,
 };
 
 pub type Keymap = fn(
@@ -17,8 +17,11 @@ pub type Keymap = fn(
 /// | :--------------------- | :-------------------------------------------
 /// | <kbd>Enter</kbd>       | Exit the tree view
 /// | <kbd>Ctrl + C</kbd>    | Interrupt the current operation
+/// | <kbd>Esc</kbd>         | Cancel the current operation
 /// | <kbd>↑</kbd>           | Move the selection up
 /// | <kbd>↓</kbd>           | Move the selection down
+/// | <kbd>←</kbd>           | Scroll to the left when not wrapping
+/// | <kbd>→</kbd>           | Scroll to the right when not wrapping
 /// | <kbd>Space</kbd>       | Toggle fold/unfold at the current node
 pub fn default(
     event: &Event,
@@ -38,7 +41,13 @@ pub fn default(
             modifiers: KeyModifiers::CONTROL,
             kind: KeyEventKind::Press,
             state: KeyEventState::NONE,
-        }) => return Err(anyhow::anyhow!("ctrl+c")),
+        }) => return Err(PromptError::Interrupted.into()),
+        Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => return Err(PromptError::Cancelled.into()),
 
         // Move cursor.
         Event::Key(KeyEvent {
@@ -75,6 +84,24 @@ pub fn default(
             tree_after_mut.tree.forward();
         }
 
+        // Scroll horizontally.
+        Event::Key(KeyEvent {
+            code: KeyCode::Left,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if !tree_after_mut.wrap => {
+            tree_after_mut.scroll_left();
+        }
+        Event::Key(KeyEvent {
+            code: KeyCode::Right,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) if !tree_after_mut.wrap => {
+            tree_after_mut.scroll_right();
+        }
+
         // Fold/Unfold
         Event::Key(KeyEvent {
             code: KeyCode::Char(' '),
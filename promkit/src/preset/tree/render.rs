@@ -1,8 +1,14 @@
 use std::cell::RefCell;
 
 use crate::{
-    crossterm::event::Event, pane::Pane, snapshot::Snapshot, switch::ActiveKeySwitcher, text, tree,
-    PaneFactory, PromptSignal,
+    crossterm::event::Event,
+    grapheme::StyledGraphemes,
+    pane::{Pane, Priority},
+    preset,
+    snapshot::Snapshot,
+    switch::ActiveKeySwitcher,
+    terminal::Click,
+    text, tree, PaneFactory, PromptSignal,
 };
 
 use super::keymap;
@@ -24,13 +30,25 @@ impl crate::Finalizer for Renderer {
     fn finalize(&mut self) -> anyhow::Result<Self::Return> {
         Ok(self.tree_snapshot.after().tree.get())
     }
+
+    fn summary(&self, result: &Self::Return) -> Option<StyledGraphemes> {
+        Some(preset::summary(
+            &self.title_snapshot.after().text,
+            result.join("/"),
+        ))
+    }
 }
 
 impl crate::Renderer for Renderer {
     fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let title = self
+            .title_snapshot
+            .create_pane(width, height)
+            .with_priority(Priority::Low);
+        let height = preset::height_below(height, &[&title]);
         vec![
-            self.title_snapshot.create_pane(width, height),
-            self.tree_snapshot.create_pane(width, height),
+            title,
+            self.tree_snapshot.create_pane(width, height).with_focus(),
         ]
     }
 
@@ -38,4 +56,16 @@ impl crate::Renderer for Renderer {
         let keymap = *self.keymap.borrow_mut().get();
         keymap(event, self)
     }
+
+    /// Moves the cursor to the clicked node, and submits it on a double-click.
+    fn evaluate_click(&mut self, click: &Click) -> anyhow::Result<PromptSignal> {
+        let Some(item) = click.item else {
+            return Ok(PromptSignal::Continue);
+        };
+        self.tree_snapshot.after_mut().tree.move_to(item);
+        if click.count > 1 {
+            return Ok(PromptSignal::Quit);
+        }
+        Ok(PromptSignal::Continue)
+    }
 }
@@ -0,0 +1,192 @@
+use std::{any::Any, cell::RefCell};
+
+use crate::{
+    preset::container::{Component, Results},
+    switch::ActiveKeySwitcher,
+    Prompt,
+};
+
+pub mod keymap;
+pub mod render;
+
+/// Decides from the earlier answers whether a step is shown.
+type Condition = Box<dyn Fn(&Results) -> bool>;
+
+/// A step of a [`Wizard`], shown if its condition holds for the earlier answers.
+pub struct Step {
+    component: Box<dyn Component>,
+    condition: Option<Condition>,
+}
+
+impl Step {
+    /// Checks if the step is to be shown, given the answers so far.
+    fn is_enabled(&self, answers: &Results) -> bool {
+        self.condition
+            .as_ref()
+            .map_or(true, |condition| condition(answers))
+    }
+}
+
+/// Asks a sequence of questions, one step at a time.
+///
+/// Each step is a component such as the prompt of a preset.
+/// When a step quits (e.g. on <kbd>Enter</kbd>), the wizard moves on to the next step,
+/// leaving the summary of the answer on the screen,
+/// and it quits after the last step.
+/// <kbd>Shift + Tab</kbd> goes back to the previous step with its answer pre-filled.
+///
+/// ```no_run
+/// use promkit::preset::{confirm::Confirm, readline::Readline, wizard::Wizard};
+///
+/// fn main() -> anyhow::Result<()> {
+///     let mut p = Wizard::new()
+///         .step(Readline::default().title("Project name").prompt()?)
+///         .step(Confirm::new("Use git?").prompt()?)
+///         .step_if(
+///             |answers| answers.get::<String>(1).is_some_and(|yes| yes == "y"),
+///             Readline::default().title("Remote URL").prompt()?,
+///         )
+///         .prompt()?;
+///     let mut answers = p.run()?;
+///     println!("{:?}", answers.take::<String>(0));
+///     println!("{:?}", answers.take::<String>(2));
+///     Ok(())
+/// }
+/// ```
+pub struct Wizard {
+    keymap: ActiveKeySwitcher<keymap::Keymap>,
+    steps: Vec<Step>,
+}
+
+impl Default for Wizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Wizard {
+    /// Constructs a `Wizard` without any steps.
+    pub fn new() -> Self {
+        Self {
+            keymap: ActiveKeySwitcher::new("default", self::keymap::default as keymap::Keymap),
+            steps: vec![],
+        }
+    }
+
+    /// Appends a step that is always shown.
+    pub fn step<C: Component + 'static>(mut self, component: C) -> Self {
+        self.steps.push(Step {
+            component: Box::new(component),
+            condition: None,
+        });
+        self
+    }
+
+    /// Appends a step that is shown only if the condition holds
+    /// for the answers of the earlier steps.
+    /// The answer of a skipped step is not available in the result.
+    pub fn step_if<C, F>(mut self, condition: F, component: C) -> Self
+    where
+        C: Component + 'static,
+        F: Fn(&Results) -> bool + 'static,
+    {
+        self.steps.push(Step {
+            component: Box::new(component),
+            condition: Some(Box::new(condition)),
+        });
+        self
+    }
+
+    pub fn register_keymap<K: AsRef<str>>(mut self, key: K, handler: keymap::Keymap) -> Self {
+        self.keymap = self.keymap.register(key, handler);
+        self
+    }
+
+    /// Creates a prompt going through the steps.
+    /// Returns a `Result` containing the `Prompt`,
+    /// whose result holds the answers of all the steps, in the order they were added.
+    pub fn prompt(self) -> anyhow::Result<Prompt<render::Renderer>> {
+        let answers = Results(
+            self.steps
+                .iter()
+                .map(|_| Box::new(()) as Box<dyn Any>)
+                .collect(),
+        );
+        let mut renderer = render::Renderer {
+            keymap: RefCell::new(self.keymap),
+            steps: self.steps,
+            current: 0,
+            visited: vec![],
+            answers,
+        };
+        renderer.skip_disabled_steps();
+        Ok(Prompt::new(renderer))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod run {
+        use super::*;
+
+        use crate::{
+            crossterm::event::{KeyCode, KeyModifiers},
+            preset::{confirm::Confirm, listbox::Listbox, readline::Readline},
+            testing::{self, key, key_with, type_text},
+        };
+
+        fn wizard() -> Prompt<render::Renderer> {
+            Wizard::new()
+                .step(Readline::default().title("Name").prompt().unwrap())
+                .step(Confirm::new("Pick a color?").prompt().unwrap())
+                .step_if(
+                    |answers| answers.get::<String>(1).is_some_and(|yes| yes == "y"),
+                    Listbox::new(["red", "blue"])
+                        .title("Color")
+                        .prompt()
+                        .unwrap(),
+                )
+                .prompt()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_back_with_prefilled_answer() {
+            let mut prompt = wizard();
+            let mut events = type_text("ab");
+            events.extend([
+                key(KeyCode::Enter),
+                key_with(KeyCode::BackTab, KeyModifiers::SHIFT),
+                key(KeyCode::Char('c')),
+                key(KeyCode::Enter),
+            ]);
+            events.extend(type_text("n"));
+            events.push(key(KeyCode::Enter));
+            let transcript = testing::run(&mut prompt.renderer, events, (30, 10)).unwrap();
+
+            // The summary of the first step is left above the second one.
+            assert_eq!("✔ Name: ab", transcript.frames[3].rows()[0]);
+
+            let mut answers = transcript.result.unwrap().unwrap();
+            assert_eq!(Some(String::from("abc")), answers.take::<String>(0));
+            assert_eq!(Some(String::from("n")), answers.take::<String>(1));
+            // The color is not asked.
+            assert_eq!(None, answers.take::<String>(2));
+        }
+
+        #[test]
+        fn test_conditional_step() {
+            let mut prompt = wizard();
+            let mut events = type_text("ab");
+            events.push(key(KeyCode::Enter));
+            events.extend(type_text("y"));
+            events.extend([key(KeyCode::Enter), key(KeyCode::Down), key(KeyCode::Enter)]);
+            let transcript = testing::run(&mut prompt.renderer, events, (30, 10)).unwrap();
+
+            let mut answers = transcript.result.unwrap().unwrap();
+            assert_eq!(Some(String::from("blue")), answers.take::<String>(2));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers},
+    preset, PromptSignal,
+};
+
+pub type Keymap = fn(
+    event: &Event,
+    renderer: &mut preset::wizard::render::Renderer,
+) -> anyhow::Result<PromptSignal>;
+
+/// Default key bindings for the wizard.
+///
+/// | Key                    | Action
+/// | :--------------------- | :-------------------------------------------
+/// | <kbd>Shift + Tab</kbd> | Go back to the previous step
+/// | Others                 | Handled by the key bindings of the current step
+pub fn default(
+    event: &Event,
+    renderer: &mut preset::wizard::render::Renderer,
+) -> anyhow::Result<PromptSignal> {
+    match event {
+        Event::Key(KeyEvent {
+            code: KeyCode::BackTab,
+            modifiers: KeyModifiers::SHIFT,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }) => renderer.back(),
+
+        _ => return renderer.evaluate_current(event),
+    }
+    Ok(PromptSignal::Continue)
+}
@@ -0,0 +1,103 @@
+use std::{any::Any, cell::RefCell};
+
+use crate::{
+    crossterm::event::Event,
+    pane::{Pane, Priority},
+    preset::container::Results,
+    switch::ActiveKeySwitcher,
+    PromptSignal,
+};
+
+use super::{keymap, Step};
+
+/// Renders the current step of a wizard
+/// below the summaries of the steps answered so far.
+pub struct Renderer {
+    /// A mutable reference to a key switcher that manages active key mappings.
+    pub keymap: RefCell<ActiveKeySwitcher<keymap::Keymap>>,
+    /// All the steps, including the ones to be skipped.
+    pub steps: Vec<Step>,
+    /// The index of the current step.
+    pub current: usize,
+    /// The indexes of the steps answered before the current one, in order.
+    pub visited: Vec<usize>,
+    /// The answers of the steps, `()` for the unanswered ones.
+    pub answers: Results,
+}
+
+impl crate::Finalizer for Renderer {
+    type Return = Results;
+
+    fn finalize(&mut self) -> anyhow::Result<Self::Return> {
+        Ok(Results(std::mem::take(&mut self.answers.0)))
+    }
+}
+
+impl Renderer {
+    /// Moves from the current step to the next one whose condition holds,
+    /// if the current step is disabled itself.
+    pub(crate) fn skip_disabled_steps(&mut self) {
+        while self.current < self.steps.len() && !self.steps[self.current].is_enabled(&self.answers)
+        {
+            self.current += 1;
+        }
+    }
+
+    /// Goes back to the previous step, restoring its answer for editing.
+    pub fn back(&mut self) {
+        if let Some(previous) = self.visited.pop() {
+            let answer = std::mem::replace(&mut self.answers.0[previous], Box::new(()));
+            self.steps[previous].component.restore(answer);
+            self.current = previous;
+        }
+    }
+
+    /// Routes the event to the current step.
+    ///
+    /// When the step quits, its answer is recorded and the wizard moves on,
+    /// or quits if there are no more steps.
+    pub fn evaluate_current(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        let Some(step) = self.steps.get_mut(self.current) else {
+            return Ok(PromptSignal::Quit);
+        };
+        if step.component.evaluate(event)? == PromptSignal::Continue {
+            return Ok(PromptSignal::Continue);
+        }
+
+        self.answers.0[self.current] = step.component.finalize()?;
+        self.visited.push(self.current);
+        self.current += 1;
+        self.skip_disabled_steps();
+        // After the last step, only the summaries are left on the screen.
+        if self.current < self.steps.len() {
+            Ok(PromptSignal::Continue)
+        } else {
+            Ok(PromptSignal::Quit)
+        }
+    }
+}
+
+impl crate::Renderer for Renderer {
+    fn create_panes(&self, width: u16, height: u16) -> Vec<Pane> {
+        let mut panes = self
+            .visited
+            .iter()
+            .filter_map(|&i| {
+                let answer: &dyn Any = self.answers.0[i].as_ref();
+                self.steps[i].component.summary(answer)
+            })
+            .map(|summary| {
+                Pane::new(summary.matrixify(width as usize, 1, 0).0, 0).with_priority(Priority::Low)
+            })
+            .collect::<Vec<_>>();
+        if let Some(step) = self.steps.get(self.current) {
+            panes.extend(step.component.create_panes(width, height));
+        }
+        panes
+    }
+
+    fn evaluate(&mut self, event: &Event) -> anyhow::Result<PromptSignal> {
+        let keymap = *self.keymap.borrow_mut().get();
+        keymap(event, self)
+    }
+}
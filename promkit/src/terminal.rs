@@ -1,19 +1,200 @@
-use std::io::{self, Write};
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
 
 use crate::{
-    crossterm::{cursor, style, terminal},
+    crossterm::{
+        cursor,
+        event::{MouseButton, MouseEvent, MouseEventKind},
+        style::{self, Color},
+        terminal,
+    },
+    grapheme::StyledGraphemes,
     pane::Pane,
+    style::StyleBuilder,
 };
 
-pub struct Terminal {
+/// A destination that the prompt is rendered to.
+///
+/// In addition to accepting the bytes written by [`Terminal`],
+/// a backend reports the size of the area available for rendering
+/// and the current cursor position within it.
+/// It is implemented for `io::Stdout` and `io::Stderr`,
+/// and can be implemented for any other writer such as an in-memory buffer.
+pub trait Backend: Write {
+    /// Returns the size of the rendering area as `(columns, rows)`.
+    fn size(&self) -> anyhow::Result<(u16, u16)>;
+
+    /// Returns the current cursor position as `(column, row)`.
+    fn position(&mut self) -> anyhow::Result<(u16, u16)>;
+}
+
+impl Backend for io::Stdout {
+    fn size(&self) -> anyhow::Result<(u16, u16)> {
+        terminal::size().map_err(anyhow::Error::from)
+    }
+
+    fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+        cursor::position().map_err(anyhow::Error::from)
+    }
+}
+
+impl Backend for io::Stderr {
+    fn size(&self) -> anyhow::Result<(u16, u16)> {
+        terminal::size().map_err(anyhow::Error::from)
+    }
+
+    fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+        query_position(self)
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for &mut B {
+    fn size(&self) -> anyhow::Result<(u16, u16)> {
+        (**self).size()
+    }
+
+    fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+        (**self).position()
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for Box<B> {
+    fn size(&self) -> anyhow::Result<(u16, u16)> {
+        (**self).size()
+    }
+
+    fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+        (**self).position()
+    }
+}
+
+/// How long to wait for the terminal to report the cursor position.
+#[cfg(unix)]
+const POSITION_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Queries the cursor position through the given writer.
+///
+/// `cursor::position` of crossterm always sends the query to stdout,
+/// which corrupts the output when stdout is redirected (e.g. piped into another program).
+/// In that case the query is sent through `writer` instead
+/// and the answer is read from the controlling terminal.
+fn query_position<W: Write + ?Sized>(writer: &mut W) -> anyhow::Result<(u16, u16)> {
+    use std::io::IsTerminal;
+
+    if io::stdout().is_terminal() {
+        return cursor::position().map_err(anyhow::Error::from);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::{io::Read, os::fd::AsRawFd};
+
+        let mut tty = std::fs::File::open("/dev/tty")?;
+        writer.write_all(b"\x1B[6n")?;
+        writer.flush()?;
+
+        // The answer has the form `ESC [ row ; column R` (1-based).
+        // Other input read in the meantime (e.g. keys typed ahead) is skipped,
+        // and the terminal is given as long to answer as `cursor::position` gives it.
+        let deadline = Instant::now() + POSITION_TIMEOUT;
+        let mut buf = Vec::new();
+        let mut byte = [0; 1];
+        loop {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            let mut fd = libc::pollfd {
+                fd: tty.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: `fd` is a single valid `pollfd` borrowed for the duration of the call.
+            match unsafe { libc::poll(&mut fd, 1, timeout.as_millis() as libc::c_int) } {
+                -1 if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted => continue,
+                -1 => return Err(io::Error::last_os_error().into()),
+                0 => {
+                    return Err(anyhow::anyhow!(
+                        "The cursor position could not be read within a normal duration"
+                    ))
+                }
+                _ => tty.read_exact(&mut byte)?,
+            }
+            match byte[0] {
+                b'\x1B' => buf = vec![b'\x1B'],
+                b'R' if buf.starts_with(b"\x1B[") => {
+                    if let Some(position) = parse_position(&buf[2..]) {
+                        return Ok(position);
+                    }
+                    buf.clear();
+                }
+                b if !buf.is_empty() => buf.push(b),
+                _ => (),
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = writer;
+        cursor::position().map_err(anyhow::Error::from)
+    }
+}
+
+/// Parses the `row;column` part of a cursor position report into a 0-based `(column, row)`.
+#[cfg(unix)]
+fn parse_position(report: &[u8]) -> Option<(u16, u16)> {
+    let (row, column) = std::str::from_utf8(report).ok()?.split_once(';')?;
+    Some((
+        column.parse::<u16>().ok()?.saturating_sub(1),
+        row.parse::<u16>().ok()?.saturating_sub(1),
+    ))
+}
+
+/// The longest interval between the clicks of a double-click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A click of the left mouse button on a row drawn by [`Terminal::draw`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Click {
+    /// The index of the clicked pane among the panes passed to `draw`.
+    pub pane: usize,
+    /// The index of the item shown on the clicked row (see [`Pane::with_items`]), if any.
+    pub item: Option<usize>,
+    /// The clicked column, counted from the start of the row
+    /// after the columns in front of the content (see [`Pane::with_indent`]),
+    /// e.g. from the inside of the border of a [`Block`](crate::block::Block).
+    pub column: usize,
+    /// The number of successive clicks at the same position, e.g. 2 for a double-click.
+    /// The successive clicks report the pane and item of the first one.
+    pub count: usize,
+}
+
+pub struct Terminal<W: Backend> {
     /// The current cursor position within the terminal.
     pub position: (u16, u16),
+    /// The backend that the panes are rendered to.
+    backend: W,
+    /// The rows drawn by the previous `draw`, starting from `position`.
+    /// `None` if the screen has to be redrawn entirely.
+    previous: Option<Vec<StyledGraphemes>>,
+    /// The position that the previous rows were drawn from.
+    previous_position: (u16, u16),
+    /// Whether the terminal cursor is shown at the caret reported by the panes.
+    cursor_visible: bool,
+    /// The index of the pane, the item and the indent of each of the previous rows,
+    /// `None` for the rows not drawn from any of the given panes.
+    targets: Vec<Option<(usize, Option<usize>, usize)>>,
+    /// The previous click and when it happened, to count successive clicks.
+    last_click: Option<(Instant, (u16, u16), Click)>,
+    /// The row that the cursor was left at, relative to `position`.
+    cursor_row: u16,
 }
 
-impl Terminal {
-    pub fn start_session(panes: &[Pane]) -> anyhow::Result<Self> {
-        let position = cursor::position()?;
-        let size = terminal::size()?;
+impl<W: Backend> Terminal<W> {
+    pub fn start_session(mut backend: W, panes: &[Pane]) -> anyhow::Result<Self> {
+        let position = backend.position()?;
+        let size = backend.size()?;
 
         // If the cursor is not at the beginning of a line (position.0 != 0),
         // there are two scenarios to consider:
@@ -23,9 +204,9 @@ impl Terminal {
         //    to ensure the next output starts correctly.
         if position.0 != 0 {
             if size.1 == position.1 + 1 {
-                crossterm::queue!(io::stdout(), terminal::ScrollUp(1))?;
+                crossterm::queue!(backend, terminal::ScrollUp(1))?;
             }
-            crossterm::queue!(io::stdout(), cursor::MoveToNextLine(1))?;
+            crossterm::queue!(backend, cursor::MoveToNextLine(1))?;
         }
 
         // Calculate the total number of rows required by all panes.
@@ -39,71 +220,677 @@ impl Terminal {
         // to maintain its relative position.
         if size.1 == position.1 + 1 {
             crossterm::queue!(
-                io::stdout(),
+                backend,
                 terminal::ScrollUp(lines as u16),
                 cursor::MoveToPreviousLine(lines as u16),
             )?;
         }
 
-        io::stdout().flush()?;
+        backend.flush()?;
 
+        let position = backend.position()?;
         Ok(Self {
-            position: cursor::position()?,
+            position,
+            backend,
+            previous: None,
+            previous_position: position,
+            cursor_visible: false,
+            targets: vec![],
+            last_click: None,
+            cursor_row: 0,
         })
     }
 
-    pub fn draw(&mut self, panes: &[Pane]) -> anyhow::Result<()> {
-        let height = terminal::size()?.1;
+    /// Returns a reference to the backend.
+    pub fn backend(&self) -> &W {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the backend.
+    pub fn backend_mut(&mut self) -> &mut W {
+        &mut self.backend
+    }
+
+    /// Discards the rows drawn previously,
+    /// so that the next `draw` redraws every row instead of only the changed ones.
+    ///
+    /// This must be called when the screen has been modified other than by `draw`,
+    /// e.g. cleared by other output.
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
+
+    /// Sets whether the terminal cursor is shown at the caret reported by the panes
+    /// (see [`Pane::with_cursor`]), e.g. for input methods and screen readers.
+    /// The cursor is hidden while no pane reports a caret.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_visible = visible;
+    }
 
-        let viewable_panes = panes
+    /// Moves the cursor below the rows drawn last, as `draw` leaves it
+    /// unless the cursor is shown at a caret.
+    ///
+    /// This must be called before the prompt finishes
+    /// so that any output that follows does not overwrite the rows.
+    pub fn park_cursor(&mut self) -> anyhow::Result<()> {
+        let height = self.backend.size()?.1;
+        let rows = self.previous.as_ref().map(|rows| rows.len()).unwrap_or(0);
+        let row = (self.position.1 + rows as u16).min(height.saturating_sub(1));
+        crossterm::execute!(self.backend, cursor::MoveTo(0, row))?;
+        self.cursor_row = row.saturating_sub(self.position.1);
+        Ok(())
+    }
+
+    /// Recomputes the row that the prompt starts from after the terminal was resized,
+    /// so that the next `draw` redraws the prompt there.
+    ///
+    /// The terminal moves the cursor along with the rows when resized,
+    /// so the start row is found by counting the rows drawn above the cursor.
+    /// Rows wider than the new width are counted as wrapped onto multiple lines,
+    /// as most terminals (e.g. tmux) reflow them when narrowed.
+    /// Unlike clearing the whole screen, this leaves the output above the prompt
+    /// and the scrollback intact.
+    pub fn reposition(&mut self) -> anyhow::Result<()> {
+        let width = (self.backend.size()?.0 as usize).max(1);
+        let cursor_row = self.backend.position()?.1;
+
+        let previous = self.previous.take().unwrap_or_default();
+        let above = previous
             .iter()
-            .filter(|pane| !pane.is_empty())
-            .collect::<Vec<&Pane>>();
+            .take(self.cursor_row as usize)
+            .enumerate()
+            .map(|(i, row)| {
+                // The first row starts from the column that the session started at.
+                let start = if i == 0 { self.position.0 as usize } else { 0 };
+                (start + row.widths()).div_ceil(width).max(1)
+            })
+            .sum::<usize>()
+            + (self.cursor_row as usize).saturating_sub(previous.len());
 
-        if height < viewable_panes.len() as u16 {
-            return crossterm::execute!(
-                io::stdout(),
-                terminal::Clear(terminal::ClearType::FromCursorDown),
-                style::Print("⚠️ Insufficient Space"),
+        self.position.1 = cursor_row.saturating_sub(above as u16);
+        if self.position.0 as usize >= width {
+            self.position.0 = 0;
+        }
+        self.targets.clear();
+        self.last_click = None;
+        Ok(())
+    }
+
+    /// Selects the panes to draw when there are more panes than rows,
+    /// since every pane needs at least one row.
+    ///
+    /// Panes are collapsed in ascending order of priority, the latter ones first among equals,
+    /// while focused panes always stay visible.
+    /// The collapsed panes are replaced by a "… N more" marker at the end,
+    /// which comes without an index unlike the panes kept.
+    fn collapse(panes: Vec<(usize, &Pane)>, height: usize) -> Vec<(Option<usize>, Cow<'_, Pane>)> {
+        if panes.len() <= height {
+            return panes
+                .into_iter()
+                .map(|(index, pane)| (Some(index), Cow::Borrowed(pane)))
+                .collect();
+        }
+
+        // Keep one row for the marker if possible.
+        let capacity = if height > 1 { height - 1 } else { height };
+        let mut ranked = (0..panes.len()).collect::<Vec<usize>>();
+        ranked.sort_by_key(|&i| {
+            (
+                std::cmp::Reverse(panes[i].1.is_focused()),
+                std::cmp::Reverse(panes[i].1.priority()),
+                i,
             )
-            .map_err(anyhow::Error::from);
+        });
+        ranked.truncate(capacity);
+        ranked.sort();
+
+        let hidden = panes.len() - ranked.len();
+        let mut kept = ranked
+            .into_iter()
+            .map(|i| (Some(panes[i].0), Cow::Borrowed(panes[i].1)))
+            .collect::<Vec<_>>();
+        if kept.len() < height {
+            kept.push((
+                None,
+                Cow::Owned(Pane::new(
+                    vec![StyledGraphemes::from_str(
+                        format!("… {} more", hidden),
+                        StyleBuilder::new().fgc(Color::DarkGrey).build(),
+                    )],
+                    0,
+                )),
+            ));
         }
+        kept
+    }
 
-        crossterm::queue!(
-            io::stdout(),
+    /// Maps a mouse event to the pane and item drawn where it happened.
+    ///
+    /// Returns `None` unless the event is a press of the left button
+    /// on one of the rows drawn by the previous `draw`.
+    /// Successive clicks at the same position within a short interval are counted
+    /// as one with the target of the first, so that a double-click can be told from a single one.
+    pub fn click(&mut self, event: &MouseEvent) -> Option<Click> {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+        let now = Instant::now();
+        let click = match self.last_click {
+            // The rows may have moved after the previous click, e.g. a list scrolled to the item,
+            // so the successive clicks count on whatever the first one hit.
+            Some((at, position, last))
+                if position == (event.column, event.row)
+                    && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL =>
+            {
+                Click {
+                    count: last.count + 1,
+                    ..last
+                }
+            }
+            _ => {
+                let row = event.row.checked_sub(self.position.1)? as usize;
+                let (pane, item, indent) = (*self.targets.get(row)?)?;
+                // The first row starts from the column that the session started at.
+                let column = if row == 0 {
+                    event.column.checked_sub(self.position.0)?
+                } else {
+                    event.column
+                };
+                Click {
+                    pane,
+                    item,
+                    column: (column as usize).saturating_sub(indent),
+                    count: 1,
+                }
+            }
+        };
+        self.last_click = Some((now, (event.column, event.row), click));
+        Some(click)
+    }
+
+    /// Erases everything drawn from the current position.
+    pub fn erase(&mut self) -> anyhow::Result<()> {
+        crossterm::execute!(
+            self.backend,
             cursor::MoveTo(self.position.0, self.position.1),
             terminal::Clear(terminal::ClearType::FromCursorDown),
         )?;
+        self.previous = Some(vec![]);
+        self.previous_position = self.position;
+        self.targets.clear();
+        self.cursor_row = 0;
+        Ok(())
+    }
+
+    /// Draws the panes from the current position.
+    ///
+    /// Only the rows that differ from the previous `draw` are written,
+    /// and the rows left over from the previous `draw` are cleared.
+    pub fn draw(&mut self, panes: &[Pane]) -> anyhow::Result<()> {
+        let height = self.backend.size()?.1;
 
-        let mut used = 0;
+        let viewable_panes = Self::collapse(
+            panes
+                .iter()
+                .enumerate()
+                .filter(|(_, pane)| !pane.is_empty())
+                .collect::<Vec<(usize, &Pane)>>(),
+            height as usize,
+        );
 
-        let mut current_cursor_y = height.saturating_sub(self.position.1);
-        for (i, pane) in viewable_panes.iter().enumerate() {
-            let rows = pane.extract(
-                1.max(
-                    (height as usize)
-                        // -1 in this context signifies the exclusion of the current pane.
-                        .saturating_sub(used + viewable_panes.len() - 1 - i),
-                ),
+        let mut rows = Vec::new();
+        let mut targets = Vec::new();
+        // The caret of the focused pane, or else of the first pane having one.
+        let mut caret: Option<(bool, (usize, usize))> = None;
+        for (i, (index, pane)) in viewable_panes.iter().enumerate() {
+            let viewport_height = 1.max(
+                (height as usize)
+                    // -1 in this context signifies the exclusion of the current pane.
+                    .saturating_sub(rows.len() + viewable_panes.len() - 1 - i),
             );
-            used += rows.len();
-            for (j, row) in rows.iter().enumerate() {
-                crossterm::queue!(io::stdout(), style::Print(row.styled_display()))?;
-
-                current_cursor_y = current_cursor_y.saturating_sub(1);
-
-                if ((i == viewable_panes.len() - 1 && j != rows.len() - 1)
-                    || i != viewable_panes.len() - 1)
-                    && current_cursor_y == 0
-                {
-                    crossterm::queue!(io::stdout(), terminal::ScrollUp(1))?;
-                    self.position.1 = self.position.1.saturating_sub(1);
+            if let Some((row, column)) = pane.extracted_cursor(viewport_height) {
+                if caret.map_or(true, |(focused, _)| !focused && pane.is_focused()) {
+                    caret = Some((pane.is_focused(), (rows.len() + row, column)));
                 }
+            }
+            // The cursor is only faked where the terminal cursor is not shown.
+            let extracted = if self.cursor_visible {
+                pane.extract(viewport_height)
+            } else {
+                pane.extract_with_fake_cursor(viewport_height)
+            };
+            let mut items = pane.extracted_items(viewport_height);
+            items.resize(extracted.len(), None);
+            targets.extend(
+                items
+                    .into_iter()
+                    .map(|item| index.map(|index| (index, item, pane.indent()))),
+            );
+            rows.extend(extracted);
+        }
+
+        // The rows drawn previously are only reusable if they are still where they were drawn.
+        let previous = match self.previous.take() {
+            Some(previous) if self.previous_position == self.position => previous,
+            _ => {
+                crossterm::queue!(
+                    self.backend,
+                    cursor::MoveTo(self.position.0, self.position.1),
+                    terminal::Clear(terminal::ClearType::FromCursorDown),
+                )?;
+                vec![]
+            }
+        };
+
+        // If the rows do not fit below the current position,
+        // scroll up to make room; the rows drawn previously move up together.
+        let overflow = (self.position.1 as usize + rows.len()).saturating_sub(height as usize);
+        if overflow > 0 {
+            crossterm::queue!(self.backend, terminal::ScrollUp(overflow as u16))?;
+            self.position.1 = self.position.1.saturating_sub(overflow as u16);
+        }
 
-                crossterm::queue!(io::stdout(), cursor::MoveToNextLine(1))?;
+        for (i, row) in rows.iter().enumerate() {
+            if previous.get(i) != Some(row) {
+                crossterm::queue!(
+                    self.backend,
+                    // The first row starts from the column that the session started at.
+                    cursor::MoveTo(
+                        if i == 0 { self.position.0 } else { 0 },
+                        self.position.1 + i as u16
+                    ),
+                    terminal::Clear(terminal::ClearType::UntilNewLine),
+                    style::Print(row.styled_display()),
+                )?;
             }
         }
-        io::stdout().flush()?;
+        if rows.len() < previous.len() {
+            crossterm::queue!(
+                self.backend,
+                cursor::MoveTo(0, self.position.1 + rows.len() as u16),
+                terminal::Clear(terminal::ClearType::FromCursorDown),
+            )?;
+        }
+
+        match caret.filter(|_| self.cursor_visible) {
+            Some((_, (row, column))) if row < rows.len() => {
+                crossterm::queue!(
+                    self.backend,
+                    cursor::MoveTo(
+                        if row == 0 { self.position.0 } else { 0 } + column as u16,
+                        self.position.1 + row as u16,
+                    ),
+                    cursor::Show,
+                )?;
+                self.cursor_row = row as u16;
+            }
+            _ => {
+                if self.cursor_visible {
+                    crossterm::queue!(self.backend, cursor::Hide)?;
+                }
+                // Leave the cursor below the rows, as if they had been printed line by line.
+                let row = (self.position.1 + rows.len() as u16).min(height.saturating_sub(1));
+                crossterm::queue!(self.backend, cursor::MoveTo(0, row))?;
+                self.cursor_row = row.saturating_sub(self.position.1);
+            }
+        }
+        self.backend.flush()?;
+
+        self.previous = Some(rows);
+        self.previous_position = self.position;
+        self.targets = targets;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// An in-memory backend with a fixed size.
+    struct Buffer {
+        bytes: Vec<u8>,
+        size: (u16, u16),
+    }
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Backend for Buffer {
+        fn size(&self) -> anyhow::Result<(u16, u16)> {
+            Ok(self.size)
+        }
+
+        fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+            Ok((0, 0))
+        }
+    }
+
+    #[cfg(unix)]
+    mod parse_position {
+        use super::*;
+
+        #[test]
+        fn test() {
+            assert_eq!(Some((4, 11)), parse_position(b"12;5"));
+            assert_eq!(Some((0, 0)), parse_position(b"1;1"));
+            assert_eq!(None, parse_position(b"12"));
+            assert_eq!(None, parse_position(b"1;a"));
+        }
+    }
+
+    mod draw {
+        use super::*;
+
+        #[test]
+        fn test_with_in_memory_backend() {
+            let panes = [Pane::new(
+                vec![StyledGraphemes::from("aa"), StyledGraphemes::from("bb")],
+                0,
+            )];
+            let buffer = Buffer {
+                bytes: vec![],
+                size: (10, 10),
+            };
+            let mut terminal = Terminal::start_session(buffer, &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            let output = String::from_utf8(terminal.backend().bytes.clone()).unwrap();
+            assert!(output.contains("aa"));
+            assert!(output.contains("bb"));
+        }
+
+        #[test]
+        fn test_only_changed_rows() {
+            let buffer = Buffer {
+                bytes: vec![],
+                size: (10, 10),
+            };
+            let panes = [Pane::new(
+                vec![StyledGraphemes::from("aa"), StyledGraphemes::from("bb")],
+                0,
+            )];
+            let mut terminal = Terminal::start_session(buffer, &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            terminal.backend_mut().bytes.clear();
+            terminal
+                .draw(&[Pane::new(
+                    vec![StyledGraphemes::from("aa"), StyledGraphemes::from("cc")],
+                    0,
+                )])
+                .unwrap();
+            let output = String::from_utf8(terminal.backend().bytes.clone()).unwrap();
+            assert!(!output.contains("aa"));
+            assert!(output.contains("cc"));
+
+            terminal.backend_mut().bytes.clear();
+            terminal.invalidate();
+            terminal
+                .draw(&[Pane::new(
+                    vec![StyledGraphemes::from("aa"), StyledGraphemes::from("cc")],
+                    0,
+                )])
+                .unwrap();
+            let output = String::from_utf8(terminal.backend().bytes.clone()).unwrap();
+            assert!(output.contains("aa"));
+            assert!(output.contains("cc"));
+        }
+
+        #[test]
+        fn test_shrink() {
+            use crate::testing::VirtualTerminal;
+
+            let panes = [Pane::new(
+                vec![
+                    StyledGraphemes::from("aaa"),
+                    StyledGraphemes::from("bbb"),
+                    StyledGraphemes::from("ccc"),
+                ],
+                0,
+            )];
+            let mut terminal =
+                Terminal::start_session(VirtualTerminal::new(10, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+            terminal
+                .draw(&[Pane::new(vec![StyledGraphemes::from("a")], 0)])
+                .unwrap();
+            assert_eq!(vec!["a", "", "", "", ""], terminal.backend().frame().rows());
+        }
+
+        #[test]
+        fn test_collapse() {
+            use crate::{pane::Priority, testing::VirtualTerminal};
+
+            let panes = [
+                Pane::new(vec![StyledGraphemes::from("title")], 0).with_priority(Priority::Low),
+                Pane::new(vec![StyledGraphemes::from("error")], 0).with_priority(Priority::Low),
+                Pane::new(vec![StyledGraphemes::from("input")], 0).with_focus(),
+                Pane::new(vec![StyledGraphemes::from("list")], 0),
+            ];
+
+            let mut terminal =
+                Terminal::start_session(VirtualTerminal::new(10, 3), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+            assert_eq!(
+                vec!["input", "list", "… 2 more"],
+                terminal.backend().frame().rows()
+            );
+
+            terminal.backend_mut().resize(10, 2);
+            terminal.invalidate();
+            terminal.draw(&panes).unwrap();
+            assert_eq!(vec!["input", "… 3 more"], terminal.backend().frame().rows());
+
+            terminal.backend_mut().resize(10, 1);
+            terminal.invalidate();
+            terminal.draw(&panes).unwrap();
+            assert_eq!(vec!["input"], terminal.backend().frame().rows());
+        }
+
+        #[test]
+        fn test_cursor() {
+            use crate::{
+                crossterm::style::ContentStyle, testing::VirtualTerminal, text_editor, PaneFactory,
+            };
+
+            let active = ContentStyle {
+                background_color: Some(Color::DarkCyan),
+                ..Default::default()
+            };
+
+            let mut state = text_editor::State {
+                texteditor: Default::default(),
+                history: Default::default(),
+                prefix: String::from("❯ "),
+                mask: Default::default(),
+                prefix_style: ContentStyle::default(),
+                active_char_style: active,
+                inactive_char_style: ContentStyle::default(),
+                edit_mode: Default::default(),
+                word_break_chars: Default::default(),
+                paste_newlines: Default::default(),
+                lines: Default::default(),
+            };
+            state
+                .texteditor
+                .insert_chars(&"abcdefghij".chars().collect::<Vec<_>>());
+            state.texteditor.backward();
+            let panes = [
+                Pane::new(vec![StyledGraphemes::from("title")], 0),
+                state.create_pane(8, 5),
+            ];
+
+            let mut terminal = Terminal::start_session(VirtualTerminal::new(8, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+            let frame = terminal.backend().frame();
+            assert_eq!((0, 3), frame.cursor);
+            // The cursor is faked on `j` while the terminal cursor is hidden.
+            assert_eq!(active, frame.cell(3, 2).unwrap().style);
+
+            terminal.set_cursor_visible(true);
+            terminal.invalidate();
+            terminal.draw(&panes).unwrap();
+            let frame = terminal.backend().frame();
+            assert_eq!(vec!["title", "❯ abcdef", "ghij"], frame.rows()[..3]);
+            // Right on `j`.
+            assert_eq!((3, 2), frame.cursor);
+            assert!(frame.cursor_visible);
+            assert_eq!(ContentStyle::default(), frame.cell(3, 2).unwrap().style);
+
+            terminal.draw(&panes[..1]).unwrap();
+            assert!(!terminal.backend().frame().cursor_visible);
+        }
+    }
+    mod click {
+        use super::*;
+
+        use crate::{crossterm::event::Event, testing};
+
+        fn mouse(event: Event) -> MouseEvent {
+            match event {
+                Event::Mouse(mouse) => mouse,
+                _ => unreachable!(),
+            }
+        }
+
+        #[test]
+        fn test() {
+            let panes = [
+                Pane::new(vec![StyledGraphemes::from("title")], 0),
+                Pane::new(vec![], 0),
+                Pane::new(
+                    vec![
+                        StyledGraphemes::from("a"),
+                        StyledGraphemes::from("b"),
+                        StyledGraphemes::from("c"),
+                    ],
+                    1,
+                )
+                .with_items(vec![Some(0), Some(1), Some(2)]),
+            ];
+            let mut terminal =
+                Terminal::start_session(testing::VirtualTerminal::new(10, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            let click = terminal.click(&mouse(testing::click(3, 2))).unwrap();
+            assert_eq!(
+                (2, Some(1), 3, 1),
+                (click.pane, click.item, click.column, click.count)
+            );
+            assert_eq!(
+                2,
+                terminal.click(&mouse(testing::click(3, 2))).unwrap().count
+            );
+            assert_eq!(
+                None,
+                terminal.click(&mouse(testing::click(0, 0))).unwrap().item
+            );
+            // Below the rows drawn.
+            assert_eq!(None, terminal.click(&mouse(testing::click(0, 4))));
+        }
+
+        #[test]
+        fn test_indent() {
+            use crate::{
+                block::{Block, Padding},
+                PaneFactory,
+            };
+
+            struct Items;
+            impl PaneFactory for Items {
+                fn create_pane(&self, _width: u16, _height: u16) -> Pane {
+                    Pane::new(vec![StyledGraphemes::from("ab")], 0).with_items(vec![Some(0)])
+                }
+            }
+
+            let panes = [Block::new(Items)
+                .padding(Padding::horizontal(1))
+                .create_pane(10, 5)];
+            let mut terminal =
+                Terminal::start_session(testing::VirtualTerminal::new(10, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            // On `b`, inside the border and the padding.
+            let click = terminal.click(&mouse(testing::click(3, 1))).unwrap();
+            assert_eq!((Some(0), 1), (click.item, click.column));
+        }
+
+        #[test]
+        fn test_horizontal_layout() {
+            use crate::{
+                layout::{Constraint, Layout},
+                listbox::{self, Listbox},
+                text, PaneFactory,
+            };
+
+            let listbox = listbox::State {
+                listbox: Listbox::from_displayable(["a", "b", "c"]),
+                cursor: String::from("> "),
+                active_item_style: None,
+                inactive_item_style: None,
+                lines: None,
+                indicator: Default::default(),
+                wrap: true,
+                horizontal_offset: 0,
+            };
+            let separator = text::State {
+                text: String::from("||"),
+                style: Default::default(),
+            };
+            let panes = [Layout::horizontal()
+                .pane(Constraint::Fixed(2), separator)
+                .pane(Constraint::Fill, &listbox)
+                .create_pane(10, 5)];
+            let mut terminal =
+                Terminal::start_session(testing::VirtualTerminal::new(10, 5), &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            // On `c`, to the right of the separator.
+            let click = terminal.click(&mouse(testing::click(4, 2))).unwrap();
+            assert_eq!((Some(2), 2), (click.item, click.column));
+        }
+    }
+    mod reposition {
+        use super::*;
+
+        use crate::testing::VirtualTerminal;
+
+        #[test]
+        fn test_keep_output_above() {
+            let mut vt = VirtualTerminal::new(10, 5);
+            vt.write_all(b"out1\r\nout2\r\n").unwrap();
+            let panes = [Pane::new(
+                vec![StyledGraphemes::from("aa"), StyledGraphemes::from("bb")],
+                0,
+            )];
+            let mut terminal = Terminal::start_session(vt, &panes).unwrap();
+            terminal.draw(&panes).unwrap();
+
+            // The top row is scrolled out to keep the cursor on the screen.
+            terminal.backend_mut().resize(10, 4);
+            terminal.reposition().unwrap();
+            terminal.draw(&panes).unwrap();
+            assert_eq!(1, terminal.position.1);
+            assert_eq!(
+                vec!["out2", "aa", "bb", ""],
+                terminal.backend().frame().rows()
+            );
+
+            terminal.backend_mut().resize(10, 6);
+            terminal.reposition().unwrap();
+            terminal.draw(&panes[..0]).unwrap();
+            assert_eq!(
+                vec!["out2", "", "", "", "", ""],
+                terminal.backend().frame().rows()
+            );
+        }
+    }
+}
@@ -0,0 +1,749 @@
+//! Utilities for exercising renderers without a real terminal.
+//!
+//! [`VirtualTerminal`] is a [`Backend`] that interprets the escape sequences
+//! written by [`Terminal`] into a grid of styled cells,
+//! and [`run`] feeds a scripted sequence of events into a [`Renderer`]
+//! while capturing every drawn frame, so that presets
+//! and custom renderers can be asserted on in ordinary `cargo test`.
+//!
+//! ```
+//! use promkit::{preset::listbox::Listbox, testing};
+//! use promkit::crossterm::event::KeyCode;
+//!
+//! let mut prompt = Listbox::new(["a", "b", "c"]).prompt().unwrap();
+//! let transcript = testing::run(
+//!     &mut prompt.renderer,
+//!     [testing::key(KeyCode::Down), testing::key(KeyCode::Enter)],
+//!     (20, 5),
+//! )
+//! .unwrap();
+//!
+//! assert_eq!("❯ b", transcript.frames.last().unwrap().rows()[0]);
+//! assert_eq!("b", transcript.result.unwrap().unwrap());
+//! ```
+
+use std::{fmt, io};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{
+    crossterm::{
+        event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+        style::ContentStyle,
+    },
+    grapheme::apply_sgr,
+    terminal::{Backend, Terminal},
+    PromptSignal, Renderer,
+};
+
+/// A single cell of the [`VirtualTerminal`] screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    /// The characters displayed in the cell.
+    /// It is a space for blank cells and empty for the trailing half of a wide character.
+    pub symbol: String,
+    /// The style that the cell was printed with.
+    pub style: ContentStyle,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: String::from(" "),
+            style: ContentStyle::default(),
+        }
+    }
+}
+
+/// A snapshot of the [`VirtualTerminal`] screen.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Vec<Vec<Cell>>,
+    /// The cursor position as `(column, row)` at the time of the snapshot.
+    pub cursor: (u16, u16),
+    /// Whether the cursor is shown at the time of the snapshot.
+    pub cursor_visible: bool,
+    /// Whether the alternate screen is shown at the time of the snapshot.
+    pub alternate_screen: bool,
+}
+
+impl Frame {
+    /// Returns the cell at the given column and row, if it exists.
+    pub fn cell(&self, column: u16, row: u16) -> Option<&Cell> {
+        self.cells
+            .get(row as usize)
+            .and_then(|cells| cells.get(column as usize))
+    }
+
+    /// Returns the text of each row with trailing blanks removed.
+    pub fn rows(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|cell| cell.symbol.as_str())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rows().join("\n"))
+    }
+}
+
+impl fmt::Debug for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "|{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The contents of the main screen saved while the alternate screen is shown.
+struct SavedScreen {
+    cells: Vec<Vec<Cell>>,
+    cursor: (u16, u16),
+}
+
+/// An in-memory terminal emulator implementing [`Backend`].
+///
+/// It understands the subset of escape sequences emitted by promkit
+/// (cursor movement, clearing, scrolling, SGR styling and the alternate screen)
+/// and ignores the rest.
+pub struct VirtualTerminal {
+    width: u16,
+    height: u16,
+    cells: Vec<Vec<Cell>>,
+    cursor: (u16, u16),
+    cursor_visible: bool,
+    style: ContentStyle,
+    /// The cells and the cursor of the main screen while the alternate screen is shown.
+    main_screen: Option<SavedScreen>,
+    /// Bytes of an incomplete escape sequence or UTF-8 character.
+    pending: Vec<u8>,
+}
+
+impl VirtualTerminal {
+    /// Creates a blank terminal with the given size and the cursor at the top left.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![vec![Cell::default(); width as usize]; height as usize],
+            cursor: (0, 0),
+            cursor_visible: true,
+            style: ContentStyle::default(),
+            main_screen: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns a snapshot of the current screen.
+    pub fn frame(&self) -> Frame {
+        Frame {
+            cells: self.cells.clone(),
+            cursor: self.cursor,
+            cursor_visible: self.cursor_visible,
+            alternate_screen: self.main_screen.is_some(),
+        }
+    }
+
+    /// Changes the size of the terminal, keeping the content that still fits.
+    ///
+    /// As in most terminals, the rows at the top are scrolled out
+    /// if the cursor would otherwise fall below the new height.
+    /// The rows are truncated rather than reflowed when narrowed.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let scrolled = (self.cursor.1 + 1).saturating_sub(height);
+        self.cells
+            .drain(..(scrolled as usize).min(self.cells.len()));
+        self.cursor.1 = self.cursor.1.saturating_sub(scrolled);
+        self.cells
+            .resize(height as usize, vec![Cell::default(); width as usize]);
+        for row in self.cells.iter_mut() {
+            row.resize(width as usize, Cell::default());
+        }
+        self.width = width;
+        self.height = height;
+        self.cursor = (
+            self.cursor.0.min(width.saturating_sub(1)),
+            self.cursor.1.min(height.saturating_sub(1)),
+        );
+    }
+
+    fn scroll_up(&mut self, n: usize) {
+        for _ in 0..n.min(self.cells.len()) {
+            self.cells.remove(0);
+            self.cells.push(vec![Cell::default(); self.width as usize]);
+        }
+    }
+
+    fn clear_cells(&mut self, row: usize, from: usize, to: usize) {
+        if let Some(cells) = self.cells.get_mut(row) {
+            for cell in cells.iter_mut().take(to).skip(from) {
+                *cell = Cell::default();
+            }
+        }
+    }
+
+    fn print(&mut self, ch: char) {
+        if self.extend_cluster(ch) {
+            return;
+        }
+
+        let width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if width == 0 {
+            // Combine zero-width characters with the previously printed cell.
+            let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+            if let Some(cell) = self.cells[row][..column.min(self.width as usize)]
+                .iter_mut()
+                .rev()
+                .find(|cell| !cell.symbol.is_empty())
+                .filter(|cell| cell.symbol != " ")
+            {
+                cell.symbol.push(ch);
+            }
+            return;
+        }
+
+        // Wrap automatically when the character does not fit in the current row.
+        if self.cursor.0 as usize + width > self.width as usize {
+            self.cursor.0 = 0;
+            if self.cursor.1 + 1 >= self.height {
+                self.scroll_up(1);
+            } else {
+                self.cursor.1 += 1;
+            }
+        }
+
+        let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+        if column + width > self.width as usize {
+            return;
+        }
+        self.cells[row][column] = Cell {
+            symbol: ch.to_string(),
+            style: self.style,
+        };
+        for i in 1..width {
+            self.cells[row][column + i] = Cell {
+                symbol: String::new(),
+                style: self.style,
+            };
+        }
+        self.cursor.0 += width as u16;
+    }
+
+    /// Appends the character to the cell printed right before the cursor
+    /// if they form a single grapheme cluster, e.g. a ZWJ sequence or a flag,
+    /// widening the cell when the cluster becomes wider.
+    fn extend_cluster(&mut self, ch: char) -> bool {
+        let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+        let cells = &self.cells[row][..column.min(self.width as usize)];
+        let Some(lead) = cells.iter().rposition(|cell| !cell.symbol.is_empty()) else {
+            return false;
+        };
+        let mut symbol = self.cells[row][lead].symbol.clone();
+        if symbol == " " {
+            return false;
+        }
+        symbol.push(ch);
+        if symbol.graphemes(true).count() != 1 {
+            return false;
+        }
+
+        let width = UnicodeWidthStr::width(symbol.as_str()).max(column - lead);
+        if lead + width > self.width as usize {
+            return false;
+        }
+        self.cells[row][lead].symbol = symbol;
+        let style = self.cells[row][lead].style;
+        for cell in &mut self.cells[row][column..lead + width] {
+            *cell = Cell {
+                symbol: String::new(),
+                style,
+            };
+        }
+        self.cursor.0 = (lead + width) as u16;
+        true
+    }
+
+    fn execute_csi(&mut self, params: &str, action: char) {
+        let private = params.starts_with('?');
+        let args = params
+            .trim_start_matches('?')
+            .split(';')
+            .map(|p| p.parse::<u16>().ok())
+            .collect::<Vec<_>>();
+        let arg = |i: usize, default: u16| args.get(i).copied().flatten().unwrap_or(default);
+        let max_row = self.height.saturating_sub(1);
+        let max_column = self.width.saturating_sub(1);
+
+        if private {
+            // Other modes such as mouse capture do not affect the screen.
+            match (arg(0, 0), action) {
+                (25, 'h' | 'l') => self.cursor_visible = action == 'h',
+                (1049, 'h') if self.main_screen.is_none() => {
+                    let blank =
+                        vec![vec![Cell::default(); self.width as usize]; self.height as usize];
+                    let cells = std::mem::replace(&mut self.cells, blank);
+                    self.main_screen = Some(SavedScreen {
+                        cells,
+                        cursor: self.cursor,
+                    });
+                }
+                (1049, 'l') => {
+                    if let Some(saved) = self.main_screen.take() {
+                        self.cells = saved.cells;
+                        self.cursor = saved.cursor;
+                        // The main screen may have been saved with another size.
+                        let (width, height) = (self.width, self.height);
+                        self.resize(width, height);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        match action {
+            'A' => self.cursor.1 = self.cursor.1.saturating_sub(arg(0, 1).max(1)),
+            'B' => self.cursor.1 = (self.cursor.1 + arg(0, 1).max(1)).min(max_row),
+            'C' => self.cursor.0 = (self.cursor.0 + arg(0, 1).max(1)).min(max_column),
+            'D' => self.cursor.0 = self.cursor.0.saturating_sub(arg(0, 1).max(1)),
+            'E' => self.cursor = (0, (self.cursor.1 + arg(0, 1).max(1)).min(max_row)),
+            'F' => self.cursor = (0, self.cursor.1.saturating_sub(arg(0, 1).max(1))),
+            'G' => self.cursor.0 = arg(0, 1).saturating_sub(1).min(max_column),
+            'H' => {
+                self.cursor = (
+                    arg(1, 1).saturating_sub(1).min(max_column),
+                    arg(0, 1).saturating_sub(1).min(max_row),
+                )
+            }
+            'J' => {
+                let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+                let width = self.width as usize;
+                match arg(0, 0) {
+                    0 => {
+                        self.clear_cells(row, column, width);
+                        for r in row + 1..self.height as usize {
+                            self.clear_cells(r, 0, width);
+                        }
+                    }
+                    1 => {
+                        for r in 0..row {
+                            self.clear_cells(r, 0, width);
+                        }
+                        self.clear_cells(row, 0, column + 1);
+                    }
+                    _ => {
+                        for r in 0..self.height as usize {
+                            self.clear_cells(r, 0, width);
+                        }
+                    }
+                }
+            }
+            'K' => {
+                let (column, row) = (self.cursor.0 as usize, self.cursor.1 as usize);
+                match arg(0, 0) {
+                    0 => self.clear_cells(row, column, self.width as usize),
+                    1 => self.clear_cells(row, 0, column + 1),
+                    _ => self.clear_cells(row, 0, self.width as usize),
+                }
+            }
+            'S' => self.scroll_up(arg(0, 1) as usize),
+            'T' => {
+                for _ in 0..(arg(0, 1) as usize).min(self.cells.len()) {
+                    self.cells.pop();
+                    self.cells
+                        .insert(0, vec![Cell::default(); self.width as usize]);
+                }
+            }
+            'm' => apply_sgr(&mut self.style, params),
+            _ => (),
+        }
+    }
+
+    /// Consumes as much of the pending input as possible.
+    fn process(&mut self) {
+        let mut consumed = 0;
+        let bytes = std::mem::take(&mut self.pending);
+
+        while consumed < bytes.len() {
+            let rest = &bytes[consumed..];
+            match rest[0] {
+                b'\x1B' => {
+                    if rest.len() < 2 {
+                        break;
+                    }
+                    if rest[1] != b'[' {
+                        // Two-byte escape sequences (e.g. save/restore cursor) are ignored.
+                        consumed += 2;
+                        continue;
+                    }
+                    match rest[2..].iter().position(|b| (0x40..=0x7E).contains(b)) {
+                        Some(end) => {
+                            let params = String::from_utf8_lossy(&rest[2..2 + end]).to_string();
+                            self.execute_csi(&params, rest[2 + end] as char);
+                            consumed += 3 + end;
+                        }
+                        None => break,
+                    }
+                }
+                b'\r' => {
+                    self.cursor.0 = 0;
+                    consumed += 1;
+                }
+                b'\n' => {
+                    if self.cursor.1 + 1 >= self.height {
+                        self.scroll_up(1);
+                    } else {
+                        self.cursor.1 += 1;
+                    }
+                    consumed += 1;
+                }
+                _ => {
+                    let len = match rest[0] {
+                        b if b < 0x80 => 1,
+                        b if b >= 0xF0 => 4,
+                        b if b >= 0xE0 => 3,
+                        _ => 2,
+                    };
+                    if rest.len() < len {
+                        break;
+                    }
+                    if let Ok(s) = std::str::from_utf8(&rest[..len]) {
+                        for ch in s.chars().filter(|ch| !ch.is_control()) {
+                            self.print(ch);
+                        }
+                    }
+                    consumed += len;
+                }
+            }
+        }
+
+        self.pending = bytes[consumed..].to_vec();
+    }
+}
+
+impl io::Write for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        self.process();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for VirtualTerminal {
+    fn size(&self) -> anyhow::Result<(u16, u16)> {
+        Ok((self.width, self.height))
+    }
+
+    fn position(&mut self) -> anyhow::Result<(u16, u16)> {
+        Ok(self.cursor)
+    }
+}
+
+/// The outcome of [`run`].
+pub struct Transcript<T> {
+    /// The frames drawn by the renderer, starting from the initial one.
+    pub frames: Vec<Frame>,
+    /// The result of the renderer:
+    /// - `Some(Ok(..))` produced by `Finalizer::finalize` after the renderer quit.
+    /// - `Some(Err(..))` returned by `Renderer::evaluate`.
+    /// - `None` if the events ran out before the renderer quit.
+    pub result: Option<anyhow::Result<T>>,
+}
+
+/// Feeds the events into the renderer in the same way as `Prompt::run`,
+/// drawing to a [`VirtualTerminal`] of the given `(width, height)`
+/// and capturing the screen after each draw.
+///
+/// `Event::Resize` resizes the virtual terminal before redrawing,
+/// as the terminal would be resized before the event arrives.
+pub fn run<R: Renderer, I: IntoIterator<Item = Event>>(
+    renderer: &mut R,
+    events: I,
+    size: (u16, u16),
+) -> anyhow::Result<Transcript<R::Return>> {
+    let mut frames = Vec::new();
+
+    let panes = renderer.create_panes(size.0, size.1);
+    let mut terminal = Terminal::start_session(VirtualTerminal::new(size.0, size.1), &panes)?;
+    terminal.draw(&panes)?;
+    frames.push(terminal.backend().frame());
+
+    for ev in events {
+        match &ev {
+            Event::Resize(width, height) => {
+                terminal.backend_mut().resize(*width, *height);
+                terminal.reposition()?;
+            }
+            _ => {
+                let click = match &ev {
+                    Event::Mouse(mouse) => terminal.click(mouse),
+                    _ => None,
+                };
+                let evaluated = match click {
+                    Some(click) => renderer.evaluate_click(&click),
+                    None => renderer.evaluate(&ev),
+                };
+                match evaluated {
+                    Ok(PromptSignal::Continue) => (),
+                    Ok(PromptSignal::Quit) => {
+                        let size = terminal.backend().size()?;
+                        terminal.draw(&renderer.create_panes(size.0, size.1))?;
+                        frames.push(terminal.backend().frame());
+                        return Ok(Transcript {
+                            frames,
+                            result: Some(renderer.finalize()),
+                        });
+                    }
+                    Err(e) => {
+                        return Ok(Transcript {
+                            frames,
+                            result: Some(Err(e)),
+                        })
+                    }
+                }
+            }
+        }
+
+        let size = terminal.backend().size()?;
+        terminal.draw(&renderer.create_panes(size.0, size.1))?;
+        frames.push(terminal.backend().frame());
+    }
+
+    Ok(Transcript {
+        frames,
+        result: None,
+    })
+}
+
+/// Creates a key press event without modifiers.
+pub fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+/// Creates a key press event with the given modifiers.
+pub fn key_with(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+/// Creates a press of the left mouse button at the given column and row of the screen.
+pub fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+/// Creates key press events typing the given text.
+pub fn type_text<S: AsRef<str>>(text: S) -> Vec<Event> {
+    text.as_ref()
+        .chars()
+        .map(|ch| key(KeyCode::Char(ch)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod virtual_terminal {
+        use std::io::Write;
+
+        use crate::{
+            crossterm::{
+                cursor, queue,
+                style::{self, Attribute, Color},
+                terminal,
+            },
+            style::StyleBuilder,
+        };
+
+        use super::*;
+
+        #[test]
+        fn test_print_and_move() {
+            let mut vt = VirtualTerminal::new(5, 3);
+            queue!(
+                vt,
+                style::Print("ab"),
+                cursor::MoveToNextLine(1),
+                style::Print("c"),
+                cursor::MoveTo(3, 2),
+                style::Print("d"),
+            )
+            .unwrap();
+            assert_eq!(vec!["ab", "c", "   d"], vt.frame().rows());
+            assert_eq!((4, 2), vt.frame().cursor);
+        }
+
+        #[test]
+        fn test_wrap_and_scroll() {
+            let mut vt = VirtualTerminal::new(3, 2);
+            write!(vt, "abcdefg").unwrap();
+            assert_eq!(vec!["def", "g"], vt.frame().rows());
+        }
+
+        #[test]
+        fn test_wide_character() {
+            let mut vt = VirtualTerminal::new(4, 1);
+            write!(vt, "あい").unwrap();
+            assert_eq!(vec!["あい"], vt.frame().rows());
+            assert_eq!("", vt.frame().cell(1, 0).unwrap().symbol);
+        }
+
+        #[test]
+        fn test_grapheme_cluster() {
+            let mut vt = VirtualTerminal::new(6, 1);
+            write!(vt, "👨\u{200d}👩\u{200d}👧🇯🇵e\u{301}").unwrap();
+            assert_eq!(vec!["👨\u{200d}👩\u{200d}👧🇯🇵e\u{301}"], vt.frame().rows());
+            assert_eq!("🇯🇵", vt.frame().cell(2, 0).unwrap().symbol);
+            assert_eq!((5, 0), vt.frame().cursor);
+        }
+
+        #[test]
+        fn test_clear() {
+            let mut vt = VirtualTerminal::new(3, 3);
+            write!(vt, "aaa\r\nbbb\r\nccc").unwrap();
+            queue!(
+                vt,
+                cursor::MoveTo(1, 1),
+                terminal::Clear(terminal::ClearType::FromCursorDown),
+            )
+            .unwrap();
+            assert_eq!(vec!["aaa", "b", ""], vt.frame().rows());
+        }
+
+        #[test]
+        fn test_style() {
+            let mut vt = VirtualTerminal::new(3, 1);
+            let bold_cyan = StyleBuilder::new()
+                .fgc(Color::DarkCyan)
+                .bgc(Color::Rgb { r: 1, g: 2, b: 3 })
+                .attrs(Attribute::Bold.into())
+                .build();
+            write!(vt, "{}b", bold_cyan.apply('a')).unwrap();
+            assert_eq!(bold_cyan, vt.frame().cell(0, 0).unwrap().style);
+            assert_eq!(
+                ContentStyle::default(),
+                vt.frame().cell(1, 0).unwrap().style
+            );
+        }
+    }
+
+    mod run {
+        use crate::{
+            crossterm::event::KeyCode,
+            preset::{checkbox::Checkbox, readline::Readline},
+            PromptError,
+        };
+
+        use super::*;
+
+        #[test]
+        fn test_readline() {
+            let mut prompt = Readline::default().title("Name").prompt().unwrap();
+            let mut events = type_text("abc");
+            events.push(key(KeyCode::Backspace));
+            events.push(key(KeyCode::Enter));
+            let transcript = run(&mut prompt.renderer, events, (20, 5)).unwrap();
+
+            assert_eq!(6, transcript.frames.len());
+            assert_eq!(vec!["Name", "❯❯", "", "", ""], transcript.frames[0].rows());
+            assert_eq!("❯❯ abc", transcript.frames[3].rows()[1]);
+            assert_eq!("ab", transcript.result.unwrap().unwrap());
+        }
+
+        #[test]
+        fn test_checkbox() {
+            let mut prompt = Checkbox::new(["a", "b", "c"]).prompt().unwrap();
+            let transcript = run(
+                &mut prompt.renderer,
+                [
+                    key(KeyCode::Char(' ')),
+                    key(KeyCode::Down),
+                    key(KeyCode::Down),
+                    key(KeyCode::Char(' ')),
+                    key(KeyCode::Enter),
+                ],
+                (20, 5),
+            )
+            .unwrap();
+
+            assert_eq!(
+                vec!["❯ ☒ c", "", "", "", ""],
+                transcript.frames.last().unwrap().rows()
+            );
+            let mut result = transcript.result.unwrap().unwrap();
+            result.sort();
+            assert_eq!(vec!["a", "c"], result);
+        }
+
+        #[test]
+        fn test_interrupt() {
+            let mut prompt = Readline::default().prompt().unwrap();
+            let transcript = run(
+                &mut prompt.renderer,
+                [key_with(KeyCode::Char('c'), KeyModifiers::CONTROL)],
+                (20, 5),
+            )
+            .unwrap();
+
+            assert_eq!(1, transcript.frames.len());
+            assert!(matches!(
+                transcript.result.unwrap().map_err(PromptError::from),
+                Err(PromptError::Interrupted)
+            ));
+        }
+
+        #[test]
+        fn test_cancel_and_eof() {
+            let mut prompt = Readline::default().prompt().unwrap();
+            let transcript = run(&mut prompt.renderer, [key(KeyCode::Esc)], (20, 5)).unwrap();
+            assert!(matches!(
+                transcript.result.unwrap().map_err(PromptError::from),
+                Err(PromptError::Cancelled)
+            ));
+
+            // Ctrl+D only signals the end of input while the input is empty.
+            let mut prompt = Readline::default().prompt().unwrap();
+            let mut events = type_text("a");
+            events.push(key_with(KeyCode::Char('d'), KeyModifiers::CONTROL));
+            let transcript = run(&mut prompt.renderer, events.clone(), (20, 5)).unwrap();
+            assert!(transcript.result.is_none());
+
+            events.insert(1, key(KeyCode::Backspace));
+            let mut prompt = Readline::default().prompt().unwrap();
+            let transcript = run(&mut prompt.renderer, events, (20, 5)).unwrap();
+            assert!(matches!(
+                transcript.result.unwrap().map_err(PromptError::from),
+                Err(PromptError::Eof)
+            ));
+        }
+
+        #[test]
+        fn test_without_quit() {
+            let mut prompt = Readline::default().prompt().unwrap();
+            let transcript = run(&mut prompt.renderer, type_text("a"), (20, 5)).unwrap();
+
+            assert_eq!(2, transcript.frames.len());
+            assert!(transcript.result.is_none());
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::mpsc;
+
+/// A message applied to the renderer of a running prompt.
+pub type Update<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+/// The sending and receiving halves of the channel of updates held by a prompt.
+pub(crate) type Channel<T> = (mpsc::Sender<Update<T>>, mpsc::Receiver<Update<T>>);
+
+/// A handle to update the renderer of a running prompt from other threads.
+///
+/// It is obtained by [`Prompt::updater`](crate::Prompt::updater)
+/// and can be cloned and moved to any thread.
+/// The updates are applied by the prompt between events in the order they were sent,
+/// and the renderer is redrawn afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{thread, time::Duration};
+///
+/// use promkit::preset::listbox::Listbox;
+///
+/// let mut p = Listbox::new(Vec::<String>::new()).prompt()?;
+/// let updater = p.updater();
+/// thread::spawn(move || {
+///     for i in 0..10 {
+///         thread::sleep(Duration::from_millis(100));
+///         // Stop once the prompt has been dropped.
+///         if updater.send(move |r| r.push_items([i])).is_err() {
+///             break;
+///         }
+///     }
+/// });
+/// println!("result: {:?}", p.run()?);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub struct Updater<T> {
+    sender: mpsc::Sender<Update<T>>,
+}
+
+impl<T> Clone for Updater<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> Updater<T> {
+    pub(crate) fn new(sender: mpsc::Sender<Update<T>>) -> Self {
+        Self { sender }
+    }
+
+    /// Sends an update to be applied to the renderer.
+    ///
+    /// Fails if the prompt has already been dropped.
+    pub fn send<F: FnOnce(&mut T) + Send + 'static>(&self, update: F) -> anyhow::Result<()> {
+        self.sender
+            .send(Box::new(update))
+            .map_err(|_| anyhow::anyhow!("Prompt has been dropped"))
+    }
+}